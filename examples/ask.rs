@@ -0,0 +1,24 @@
+use color_eyre::Result;
+use tui_prompts::{ask_confirm, ask_password, ask_select, ask_text};
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let Some(name) = ask_text("Name")? else {
+        return Ok(());
+    };
+    let Some(language) = ask_select("Language", ["rust", "python", "typescript"])? else {
+        return Ok(());
+    };
+    let Some(password) = ask_password("Password")? else {
+        return Ok(());
+    };
+    let Some(subscribe) = ask_confirm("Subscribe to the newsletter?")? else {
+        return Ok(());
+    };
+    println!(
+        "{name} likes {language}, has a password of {} characters and {} subscribe",
+        password.expose_secret().chars().count(),
+        if subscribe { "did" } else { "did not" }
+    );
+    Ok(())
+}
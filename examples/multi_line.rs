@@ -79,6 +79,7 @@ impl<'a> App<'a> {
     /// split the frame into 2 areas:
     /// - prompt area
     /// - debug area
+    ///
     /// The debug area is only visible if the `debug` flag is set.
     fn split_layout(&self, area: Rect) -> (Rect, Rect) {
         if self.debug {
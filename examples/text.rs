@@ -5,7 +5,7 @@ use std::{thread::sleep, time::Duration};
 use clap::Parser;
 use color_eyre::Result;
 use ratatui::{
-    crossterm::event::{self, Event, KeyEvent, KeyModifiers},
+    crossterm::event::{self, Event},
     prelude::*,
     widgets::*,
 };
@@ -16,6 +16,9 @@ use tui_prompts::prelude::*;
 struct Cli {
     #[arg(short, long)]
     debug: bool,
+    /// Render the prompts using only ASCII characters.
+    #[arg(short, long)]
+    ascii: bool,
 }
 
 fn main() -> Result<()> {
@@ -25,35 +28,46 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default)]
 struct App<'a> {
     debug: bool,
-    current_field: Field,
-    username_state: TextState<'a>,
-    password_state: TextState<'a>,
-    invisible_state: TextState<'a>,
-}
-
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
-enum Field {
-    #[default]
-    Username,
-    Password,
-    Invisible,
+    form: FormState<'a>,
 }
 
 impl<'a> App<'a> {
     pub fn new(cli: Cli) -> Self {
+        // honor NO_COLOR and TERM=dumb
+        let theme = if cli.ascii {
+            Theme::ascii()
+        } else {
+            Theme::from_env()
+        };
+        let form = FormState::new()
+            .with_field(FormField::new(
+                TextPrompt::from("Username").with_theme(theme.clone()),
+                TextState::new(),
+            ))
+            .with_field(FormField::new(
+                TextPrompt::from("Password")
+                    .with_theme(theme.clone())
+                    .with_render_style(TextRenderStyle::Password),
+                TextState::new().with_secret(true),
+            ))
+            .with_field(FormField::new(
+                TextPrompt::from("Invisible")
+                    .with_theme(theme)
+                    .with_render_style(TextRenderStyle::Invisible),
+                TextState::new(),
+            ));
         Self {
             debug: cli.debug,
-            ..Default::default()
+            form,
         }
     }
 
     pub fn run(&mut self) -> Result<()> {
         let mut tui = Tui::new()?;
-        *self.current_state().focus_state_mut() = FocusState::Focused;
-        while !self.is_finished() {
+        while !self.form.is_finished() {
             self.handle_events()?;
             tui.draw(|frame| self.draw_ui(frame))?;
         }
@@ -66,30 +80,26 @@ impl<'a> App<'a> {
     fn handle_events(&mut self) -> Result<()> {
         if event::poll(Duration::from_millis(16))? {
             if let Event::Key(key_event) = event::read()? {
-                self.handle_key_event(key_event);
+                self.form.handle_key_event(key_event);
             }
         }
         Ok(())
     }
 
     fn draw_ui(&mut self, frame: &mut Frame) {
-        let (username_area, password_area, invisible_area, value_area, debug_area) =
-            self.split_layout(frame.size());
-        self.draw_text_prompt(frame, username_area);
-        self.draw_password_prompt(frame, password_area);
-        self.draw_invisible_prompt(frame, invisible_area);
+        let (form_area, value_area, debug_area) = self.split_layout(frame.size());
+        Form::new().draw(frame, form_area, &mut self.form);
         self.draw_state_value(frame, value_area);
         self.draw_debug(frame, debug_area);
     }
 
-    /// split the frame into 5 areas:
-    /// - username prompt
-    /// - password prompt
-    /// - invisible prompt
+    /// split the frame into 3 areas:
+    /// - the form with the username, password and invisible prompts
     /// - state value
     /// - debug area
+    ///
     /// The debug area is only visible if the `debug` flag is set.
-    fn split_layout(&self, area: Rect) -> (Rect, Rect, Rect, Rect, Rect) {
+    fn split_layout(&self, area: Rect) -> (Rect, Rect, Rect) {
         let (prompt_area, debug_area) = if self.debug {
             let areas = Layout::default()
                 .direction(Direction::Horizontal)
@@ -101,31 +111,17 @@ impl<'a> App<'a> {
         };
         let areas = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(vec![Constraint::Length(1); 4])
+            .constraints(vec![Constraint::Length(3), Constraint::Length(1)])
             .split(prompt_area);
-        (areas[0], areas[1], areas[2], areas[3], debug_area)
-    }
-
-    fn draw_text_prompt(&mut self, frame: &mut Frame, username_area: Rect) {
-        TextPrompt::from("Username").draw(frame, username_area, &mut self.username_state);
-    }
-
-    fn draw_password_prompt(&mut self, frame: &mut Frame, password_area: Rect) {
-        TextPrompt::from("Password")
-            .with_render_style(TextRenderStyle::Password)
-            .draw(frame, password_area, &mut self.password_state);
+        (areas[0], areas[1], debug_area)
     }
 
-    fn draw_invisible_prompt(&mut self, frame: &mut Frame, invisible_area: Rect) {
-        TextPrompt::from("Invisible")
-            .with_render_style(TextRenderStyle::Invisible)
-            .draw(frame, invisible_area, &mut self.invisible_state);
-    }
-
-    /// draw the value of the current state underneath the prompts.
+    /// draw the value of the focused field underneath the prompts.
     fn draw_state_value(&mut self, frame: &mut Frame, value_area: Rect) {
-        let state = self.current_state();
-        let state = format!("  Value: {}", state.value());
+        let Some(field) = self.form.focused().and_then(|index| self.form.field(index)) else {
+            return;
+        };
+        let state = format!("  Value: {}", field.state().value());
         frame.render_widget(
             Paragraph::new(state).style(Style::new().dark_gray()),
             value_area,
@@ -146,69 +142,4 @@ impl<'a> App<'a> {
             area,
         );
     }
-
-    fn is_finished(&self) -> bool {
-        self.username_state.is_finished()
-            && self.password_state.is_finished()
-            && self.invisible_state.is_finished()
-    }
-
-    fn handle_key_event(&mut self, key_event: KeyEvent) {
-        match (key_event.code, key_event.modifiers) {
-            (event::KeyCode::Enter, _) => self.submit(),
-            (event::KeyCode::Tab, KeyModifiers::NONE) => self.focus_next(),
-            (event::KeyCode::BackTab, KeyModifiers::SHIFT) => self.focus_prev(),
-            _ => self.focus_handle_event(key_event),
-        }
-    }
-
-    fn focus_handle_event(&mut self, key_event: KeyEvent) {
-        let state = self.current_state();
-        state.handle_key_event(key_event);
-    }
-
-    fn focus_next(&mut self) {
-        self.current_state().blur();
-        self.current_field = self.next_field();
-        self.current_state().focus();
-    }
-
-    fn focus_prev(&mut self) {
-        self.current_state().blur();
-        self.current_field = self.prev_field();
-        self.current_state().focus();
-    }
-
-    fn submit(&mut self) {
-        self.current_state().complete();
-        if self.current_state().is_finished() && !self.is_finished() {
-            self.current_state().blur();
-            self.current_field = self.next_field();
-            self.current_state().focus();
-        }
-    }
-
-    fn next_field(&mut self) -> Field {
-        match self.current_field {
-            Field::Username => Field::Password,
-            Field::Password => Field::Invisible,
-            Field::Invisible => Field::Username,
-        }
-    }
-
-    fn prev_field(&mut self) -> Field {
-        match self.current_field {
-            Field::Username => Field::Invisible,
-            Field::Password => Field::Username,
-            Field::Invisible => Field::Password,
-        }
-    }
-
-    fn current_state(&mut self) -> &mut TextState<'a> {
-        match self.current_field {
-            Field::Username => &mut self.username_state,
-            Field::Password => &mut self.password_state,
-            Field::Invisible => &mut self.invisible_state,
-        }
-    }
 }
@@ -0,0 +1,258 @@
+use std::{path::PathBuf, time::Duration};
+
+use time::{Date, Time};
+
+use crate::{prelude::*, Secret, State};
+
+/// The typed answer of a prompt, see [`ToAnswer`].
+#[derive(Debug)]
+pub enum Answer {
+    /// The value of a text prompt.
+    Text(String),
+    /// The value of a secret text prompt, e.g. a password.
+    Secret(Secret),
+    /// The answer of a confirm prompt.
+    Bool(bool),
+    /// The date picked by a date prompt.
+    Date(Date),
+    /// The time of a time prompt.
+    Time(Time),
+    /// The duration of a duration prompt.
+    Duration(Duration),
+    /// The path of a path prompt.
+    Path(PathBuf),
+    /// The files picked by a file picker.
+    Paths(Vec<PathBuf>),
+}
+
+impl Answer {
+    /// The text of a [`Answer::Text`].
+    #[must_use]
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_secret(&self) -> Option<&Secret> {
+        match self {
+            Self::Secret(secret) => Some(secret),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_date(&self) -> Option<Date> {
+        match self {
+            Self::Date(date) => Some(*date),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_time(&self) -> Option<Time> {
+        match self {
+            Self::Time(time) => Some(*time),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_duration(&self) -> Option<Duration> {
+        match self {
+            Self::Duration(duration) => Some(*duration),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_path(&self) -> Option<&PathBuf> {
+        match self {
+            Self::Path(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_paths(&self) -> Option<&[PathBuf]> {
+        match self {
+            Self::Paths(paths) => Some(paths),
+            _ => None,
+        }
+    }
+}
+
+/// The state of a prompt that has a typed answer once it is done.
+pub trait ToAnswer {
+    /// The answer of the prompt.
+    fn to_answer(&self) -> Answer;
+}
+
+impl ToAnswer for TextState<'_> {
+    /// The value, as a [`Answer::Secret`] if the value is secret.
+    fn to_answer(&self) -> Answer {
+        if self.is_secret() {
+            Answer::Secret(Secret::from(self.value().to_string()))
+        } else {
+            Answer::Text(self.value().to_string())
+        }
+    }
+}
+
+impl ToAnswer for PasswordConfirmState<'_> {
+    fn to_answer(&self) -> Answer {
+        Answer::Secret(Secret::from(self.password().value().to_string()))
+    }
+}
+
+impl ToAnswer for ConfirmState<'_> {
+    /// The answer, which is `false` until the prompt is answered.
+    fn to_answer(&self) -> Answer {
+        Answer::Bool(self.answer().unwrap_or_default())
+    }
+}
+
+impl ToAnswer for AutocompleteState<'_> {
+    fn to_answer(&self) -> Answer {
+        Answer::Text(self.value().to_string())
+    }
+}
+
+impl ToAnswer for PathState<'_> {
+    fn to_answer(&self) -> Answer {
+        Answer::Path(self.path())
+    }
+}
+
+impl ToAnswer for FilePickerState<'_> {
+    fn to_answer(&self) -> Answer {
+        Answer::Paths(self.selected().to_vec())
+    }
+}
+
+impl ToAnswer for DateState<'_> {
+    fn to_answer(&self) -> Answer {
+        Answer::Date(self.date())
+    }
+}
+
+impl ToAnswer for TimeState<'_> {
+    fn to_answer(&self) -> Answer {
+        Answer::Time(self.time())
+    }
+}
+
+impl ToAnswer for DurationState<'_> {
+    fn to_answer(&self) -> Answer {
+        Answer::Duration(self.duration())
+    }
+}
+
+/// The answers of a questionnaire by the names of the questions, in the order they were given.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: Vec<(String, Answer)>,
+}
+
+impl Answers {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            answers: Vec::new(),
+        }
+    }
+
+    /// The answer to the question with the name, if it was answered.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Answer> {
+        self.answers
+            .iter()
+            .find(|(answer_name, _)| answer_name == name)
+            .map(|(_, answer)| answer)
+    }
+
+    /// Sets the answer to the question with the name, replacing any previous answer.
+    pub fn insert(&mut self, name: impl Into<String>, answer: Answer) {
+        let name = name.into();
+        self.answers.retain(|(answer_name, _)| *answer_name != name);
+        self.answers.push((name, answer));
+    }
+
+    /// Whether the question with the name was answered.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// The names and answers, in the order they were given.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Answer)> {
+        self.answers
+            .iter()
+            .map(|(name, answer)| (name.as_str(), answer))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::{date, time};
+
+    use super::*;
+
+    #[test]
+    fn text_answer() {
+        let state = TextState::new().with_value("Ada");
+        assert_eq!(state.to_answer().as_text(), Some("Ada"));
+    }
+
+    #[test]
+    fn secret_answer() {
+        let state = TextState::new().with_secret(true).with_value("hunter2");
+        let answer = state.to_answer();
+        assert_eq!(answer.as_text(), None);
+        assert_eq!(answer.as_secret().unwrap().expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn typed_answers() {
+        let date = DateState::from(date!(2024 - 02 - 14)).to_answer();
+        assert_eq!(date.as_date(), Some(date!(2024 - 02 - 14)));
+        let time = TimeState::from(time!(14:30)).to_answer();
+        assert_eq!(time.as_time(), Some(time!(14:30)));
+        let duration = DurationState::from(Duration::from_secs(90)).to_answer();
+        assert_eq!(duration.as_duration(), Some(Duration::from_secs(60)));
+        assert_eq!(duration.as_text(), None);
+    }
+
+    #[test]
+    fn answers() {
+        let mut answers = Answers::new();
+        answers.insert("name", Answer::Text("Ada".into()));
+        answers.insert("date", Answer::Date(date!(2024 - 02 - 14)));
+        answers.insert("name", Answer::Text("Grace".into()));
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers.get("name").and_then(Answer::as_text), Some("Grace"));
+        assert!(!answers.contains("email"));
+        let names: Vec<_> = answers.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["date", "name"]);
+    }
+}
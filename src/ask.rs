@@ -0,0 +1,87 @@
+use std::{borrow::Cow, io};
+
+use crate::{prelude::*, Secret, State};
+
+/// Asks for a line of text, see [`ask`].
+///
+/// Returns `None` if the prompt is aborted.
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be set up, drawn to or read from.
+///
+/// ```no_run
+/// let name = tui_prompts::ask_text("Name")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn ask_text(message: impl Into<Cow<'static, str>>) -> io::Result<Option<String>> {
+    let prompt = TextPrompt::new(message.into()).with_theme(Theme::from_env());
+    let state = ask(prompt, TextState::new())?;
+    Ok(state.map(|state| state.value().to_string()))
+}
+
+/// Asks for a password, which is rendered masked, see [`ask`].
+///
+/// Returns `None` if the prompt is aborted.
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be set up, drawn to or read from.
+pub fn ask_password(message: impl Into<Cow<'static, str>>) -> io::Result<Option<Secret>> {
+    let prompt = TextPrompt::new(message.into())
+        .with_theme(Theme::from_env())
+        .with_render_style(TextRenderStyle::Password);
+    let state = ask(prompt, TextState::new().with_secret(true))?;
+    Ok(state.map(|mut state| state.take_secret()))
+}
+
+/// Asks a yes / no question, see [`ask`].
+///
+/// Returns `None` if the prompt is aborted.
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be set up, drawn to or read from.
+pub fn ask_confirm(message: impl Into<Cow<'static, str>>) -> io::Result<Option<bool>> {
+    let prompt = ConfirmPrompt::new(message.into()).with_theme(Theme::from_env());
+    let state = ask(prompt, ConfirmState::new())?;
+    Ok(state.and_then(|state| state.answer()))
+}
+
+/// Asks to select one of the choices, which are filtered as the user types, see [`ask`].
+///
+/// Returns `None` if the prompt is aborted.
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be set up, drawn to or read from.
+pub fn ask_select<I, T>(
+    message: impl Into<Cow<'static, str>>,
+    choices: I,
+) -> io::Result<Option<String>>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<Cow<'static, str>>,
+{
+    let prompt = AutocompletePrompt::new(message.into()).with_theme(Theme::from_env());
+    let state = ask(prompt, AutocompleteState::new(choices))?;
+    Ok(state.map(|state| state.value().to_string()))
+}
+
+/// Asks a prompt in the terminal, returning its state once it is done, or `None` if it is aborted.
+///
+/// The prompt is rendered inline below the cursor on stderr, with raw mode enabled until the
+/// prompt is finished. The finished prompt is left in the scrollback, see [`InlineRunner`]. If
+/// stdin is not a terminal, the prompt is answered from the next line of stdin instead, see
+/// [`NonInteractive`].
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be set up, drawn to or read from.
+pub fn ask<P, S>(prompt: P, state: S) -> io::Result<Option<S>>
+where
+    P: Prompt<State = S> + Clone,
+    S: State,
+{
+    InlineRunner::stderr()?.run(prompt, state)
+}
@@ -0,0 +1,351 @@
+use std::{
+    future::Future,
+    io::{self, Stderr},
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+use crossterm::event::{Event, EventStream};
+use futures::{Stream, StreamExt};
+use ratatui::prelude::*;
+use tokio::{sync::Notify, time::Instant};
+
+use crate::{
+    inline_runner::{finish, handle_event, required_height},
+    is_interactive,
+    prelude::*,
+    NonInteractive, State,
+};
+
+/// Runs prompts in an inline viewport like an [`InlineRunner`], reading key events from
+/// crossterm's [`EventStream`] in a tokio runtime (requires the `async` feature).
+///
+/// While a prompt is pending, it can be cancelled by a future, it is aborted after the timeout, and
+/// other tasks can change its state and request a redraw through a [`Redraw`] handle.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use tui_prompts::prelude::*;
+///
+/// # async fn run() -> std::io::Result<()> {
+/// let mut runner = AsyncRunner::stderr()?.with_timeout(Duration::from_secs(30));
+/// let name = runner.run(TextPrompt::from("Name"), TextState::new()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncRunner<B: Backend> {
+    runner: InlineRunner<B>,
+    timeout: Option<Duration>,
+    redraw: Redraw,
+}
+
+/// A handle that requests a redraw of the prompt pending in an [`AsyncRunner`], e.g. after
+/// another task changed its shared state.
+///
+/// A request made while no prompt is pending redraws the next prompt once it is drawn.
+#[derive(Debug, Clone, Default)]
+pub struct Redraw(Arc<Notify>);
+
+impl Redraw {
+    pub fn request(&self) {
+        self.0.notify_one();
+    }
+}
+
+impl AsyncRunner<CrosstermBackend<Stderr>> {
+    /// Creates a runner that renders to stderr, with raw mode enabled until it is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if raw mode cannot be enabled.
+    pub fn stderr() -> io::Result<Self> {
+        Ok(Self::from(InlineRunner::stderr()?))
+    }
+}
+
+impl<B: Backend> From<InlineRunner<B>> for AsyncRunner<B> {
+    fn from(runner: InlineRunner<B>) -> Self {
+        Self {
+            runner,
+            timeout: None,
+            redraw: Redraw::default(),
+        }
+    }
+}
+
+impl<B: Backend> AsyncRunner<B> {
+    /// Creates a runner that renders to the backend, which is expected to be in raw mode.
+    #[must_use]
+    pub fn new(backend: B) -> Self {
+        Self::from(InlineRunner::new(backend))
+    }
+
+    /// Sets the maximum number of lines of the viewport, see [`InlineRunner::with_max_height`].
+    #[must_use]
+    pub fn with_max_height(mut self, max_height: u16) -> Self {
+        self.runner = self.runner.with_max_height(max_height);
+        self
+    }
+
+    /// Sets the time after which a pending prompt is aborted and the run returns an error of kind
+    /// [`io::ErrorKind::TimedOut`].
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    #[must_use]
+    pub const fn backend(&self) -> &B {
+        self.runner.backend()
+    }
+
+    /// A handle that requests a redraw of the pending prompt, which can be sent to other tasks.
+    #[must_use]
+    pub fn redraw_handle(&self) -> Redraw {
+        self.redraw.clone()
+    }
+
+    /// Runs the prompt until it is finished.
+    ///
+    /// Returns the state once the prompt is done, or `None` if it is aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing to the terminal or reading an event fails, or if the prompt
+    /// times out.
+    pub async fn run<P, S>(&mut self, prompt: P, state: S) -> io::Result<Option<S>>
+    where
+        P: Prompt<State = S> + Clone,
+        S: State,
+    {
+        self.run_until(prompt, state, std::future::pending::<()>())
+            .await
+    }
+
+    /// Runs the prompt until it is finished or `cancel` completes, which aborts the prompt, e.g.
+    /// `token.cancelled()` of a cancellation token or the receiver of a oneshot channel.
+    ///
+    /// Returns the state once the prompt is done, or `None` if it is aborted or cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing to the terminal or reading an event fails, or if the prompt
+    /// times out.
+    pub async fn run_until<P, S, F>(
+        &mut self,
+        prompt: P,
+        state: S,
+        cancel: F,
+    ) -> io::Result<Option<S>>
+    where
+        P: Prompt<State = S> + Clone,
+        S: State,
+        F: Future,
+    {
+        let state = Mutex::new(state);
+        let status = self.run_shared(prompt, &state, cancel).await?;
+        let state = state.into_inner().unwrap_or_else(PoisonError::into_inner);
+        Ok(status.is_done().then_some(state))
+    }
+
+    /// Runs the prompt over a state shared with other tasks until it is finished or `cancel`
+    /// completes, returning the status of the prompt.
+    ///
+    /// Other tasks can change the state while the prompt is pending, and request a redraw through
+    /// the [`redraw_handle`](Self::redraw_handle).
+    ///
+    /// If stdin is not a terminal, the prompt is answered from the next line of stdin instead, see
+    /// [`NonInteractive`]. The line is read without a timeout or cancellation, blocking the task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing to the terminal or reading an event fails, or if the prompt
+    /// times out.
+    pub async fn run_shared<P, S, F>(
+        &mut self,
+        prompt: P,
+        state: &Mutex<S>,
+        cancel: F,
+    ) -> io::Result<Status>
+    where
+        P: Prompt<State = S> + Clone,
+        S: State,
+        F: Future,
+    {
+        if !is_interactive() {
+            let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+            NonInteractive::stdio().answer(&prompt, &mut *state)?;
+            return Ok(state.status());
+        }
+        self.run_with(prompt, state, EventStream::new(), cancel)
+            .await
+    }
+
+    /// Runs the prompt until it is finished, handling the events of the stream.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) async fn run_with<P, S, E, F>(
+        &mut self,
+        prompt: P,
+        state: &Mutex<S>,
+        mut events: E,
+        cancel: F,
+    ) -> io::Result<Status>
+    where
+        P: Prompt<State = S> + Clone,
+        S: State,
+        E: Stream<Item = io::Result<Event>> + Unpin,
+        F: Future,
+    {
+        let lock = || state.lock().unwrap_or_else(PoisonError::into_inner);
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let timeout = async move {
+            match deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline).await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::pin!(cancel, timeout);
+        let redraw = Arc::clone(&self.redraw.0);
+        let mut timed_out = false;
+
+        lock().focus();
+        let (width, max_height) = self.runner.bounds()?;
+        let mut height = required_height(&prompt, &mut *lock(), width, max_height);
+        let mut terminal = self.runner.terminal(height)?;
+        loop {
+            if lock().status().is_finished() {
+                break;
+            }
+            terminal.draw(|frame| prompt.clone().draw(frame, frame.size(), &mut *lock()))?;
+            // the state is not locked while waiting, so that other tasks can change it
+            tokio::select! {
+                event = events.next() => match event {
+                    Some(Ok(event)) => handle_event(&mut *lock(), event),
+                    Some(Err(err)) => return Err(err),
+                    None => lock().abort(),
+                },
+                () = redraw.notified() => {}
+                _ = &mut cancel => lock().abort(),
+                () = &mut timeout => {
+                    lock().abort();
+                    timed_out = true;
+                }
+            }
+            let next_height = required_height(&prompt, &mut *lock(), width, max_height);
+            if next_height != height {
+                // see InlineRunner::run_with
+                terminal.clear()?;
+                drop(terminal);
+                height = next_height;
+                terminal = self.runner.terminal(height)?;
+            }
+        }
+        let mut state = lock();
+        finish(&mut terminal, prompt, &mut *state, width, max_height)?;
+        if timed_out {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "the prompt timed out",
+            ));
+        }
+        Ok(state.status())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use ratatui::{
+        backend::TestBackend,
+        crossterm::event::{KeyCode, KeyEvent},
+    };
+
+    use super::*;
+
+    /// The key events, followed by no more events.
+    fn events(keys: &[KeyCode]) -> impl Stream<Item = io::Result<Event>> + Unpin {
+        let keys: Vec<_> = keys
+            .iter()
+            .map(|key| Ok(Event::Key(KeyEvent::from(*key))))
+            .collect();
+        stream::iter(keys).chain(stream::pending())
+    }
+
+    fn runner() -> AsyncRunner<TestBackend> {
+        AsyncRunner::new(TestBackend::new(20, 3))
+    }
+
+    fn prompt() -> TextPrompt<'static> {
+        TextPrompt::from("Name").with_theme(Theme::new().no_color())
+    }
+
+    #[tokio::test]
+    async fn done() {
+        let mut runner = runner();
+        let state = Mutex::new(TextState::new());
+        let status = runner
+            .run_with(
+                prompt(),
+                &state,
+                events(&[KeyCode::Char('A'), KeyCode::Enter]),
+                std::future::pending::<()>(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, Status::Done);
+        assert_eq!(state.into_inner().unwrap().value(), "A");
+        runner.backend().assert_buffer(&Buffer::with_lines([
+            "✔ Name › A          ",
+            "                    ",
+            "                    ",
+        ]));
+    }
+
+    #[tokio::test]
+    async fn cancelled() {
+        let mut runner = runner();
+        let state = Mutex::new(TextState::new());
+        let status = runner
+            .run_with(prompt(), &state, events(&[]), async {})
+            .await
+            .unwrap();
+        assert_eq!(status, Status::Aborted);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timed_out() {
+        let mut runner = runner().with_timeout(Duration::from_secs(5));
+        let state = Mutex::new(TextState::new());
+        let err = runner
+            .run_with(prompt(), &state, events(&[]), std::future::pending::<()>())
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert_eq!(state.into_inner().unwrap().status(), Status::Aborted);
+    }
+
+    #[tokio::test]
+    async fn redraw_shared_state() {
+        let mut runner = runner();
+        let redraw = runner.redraw_handle();
+        let state = Arc::new(Mutex::new(TextState::new()));
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let shared = Arc::clone(&state);
+        let update = async move {
+            shared.lock().unwrap().complete();
+            redraw.request();
+            // keep the sender alive until the prompt is finished
+            std::future::pending::<()>().await;
+            drop(tx);
+        };
+        let run = runner.run_with(prompt(), &state, events(&[]), rx);
+        let status = tokio::select! {
+            status = run => status.unwrap(),
+            () = update => unreachable!(),
+        };
+        assert_eq!(status, Status::Done);
+    }
+}
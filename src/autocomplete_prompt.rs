@@ -0,0 +1,207 @@
+use std::borrow::Cow;
+
+use crate::prelude::*;
+
+use ratatui::{
+    prelude::*,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A prompt widget that filters a list of suggestions as the user types.
+///
+/// The input is rendered on the first line, followed by a dropdown of the matching suggestions
+/// while the prompt is pending. Matched characters are rendered in the highlight style of the
+/// theme, and the highlighted suggestion is reversed. See [`AutocompleteState`] for the behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutocompletePrompt<'a> {
+    /// The message to display before the input.
+    message: Cow<'a, str>,
+    /// The maximum number of suggestions displayed at once.
+    max_visible: usize,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
+}
+
+impl<'a> AutocompletePrompt<'a> {
+    #[must_use]
+    pub const fn new(message: Cow<'a, str>) -> Self {
+        Self {
+            message,
+            max_visible: 5,
+            theme: Theme::new(),
+        }
+    }
+
+    /// Sets the maximum number of suggestions displayed at once.
+    ///
+    /// The dropdown scrolls to keep the highlighted suggestion visible. Defaults to 5.
+    #[must_use]
+    pub const fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// The lines of the dropdown, scrolled so that the highlighted suggestion is visible.
+    fn dropdown(&self, state: &AutocompleteState) -> Vec<Line<'static>> {
+        let highlighted = state.highlighted().unwrap_or(0);
+        let offset = (highlighted + 1).saturating_sub(self.max_visible);
+        state
+            .matches()
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(self.max_visible)
+            .map(|(index, m)| {
+                let suggestion = &state.suggestions()[m.index];
+                let mut spans = vec![Span::raw("  ")];
+                spans.extend(self.theme.highlight_matches(suggestion, &m.positions));
+                let mut line = Line::from(spans);
+                if state.highlighted() == Some(index) {
+                    line = line.patch_style(Style::new().reversed());
+                }
+                line
+            })
+            .collect()
+    }
+}
+
+impl Prompt for AutocompletePrompt<'_> {
+    /// Draws the prompt widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if state.is_focused() {
+            frame.set_cursor(state.cursor().0, state.cursor().1);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for AutocompletePrompt<'a> {
+    type State = AutocompleteState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let [input_area, dropdown_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+
+        let input_prompt = TextPrompt::new(self.message.clone()).with_theme(self.theme.clone());
+        input_prompt.render(input_area, buf, state.input_mut());
+
+        if !state.is_finished() {
+            let dropdown = self.dropdown(state);
+            Text::from(dropdown).render(dropdown_area, buf);
+        }
+    }
+}
+
+impl<T> From<T> for AutocompletePrompt<'static>
+where
+    T: Into<Cow<'static, str>>,
+{
+    fn from(message: T) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+
+    const LANGUAGES: [&str; 4] = ["rust", "ruby", "python", "typescript"];
+
+    fn render<'a>(prompt: AutocompletePrompt<'a>, state: &mut AutocompleteState<'a>) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 4));
+        prompt.render(buffer.area, &mut buffer, state);
+        buffer
+    }
+
+    #[test]
+    fn new() {
+        let prompt = AutocompletePrompt::from("Language");
+        assert_eq!(prompt.message, "Language");
+        assert_eq!(prompt.max_visible, 5);
+        assert_eq!(prompt.theme, Theme::default());
+    }
+
+    #[test]
+    fn render_pending() {
+        let prompt = AutocompletePrompt::from("Language").with_theme(Theme::new().no_color());
+        let mut state = AutocompleteState::new(LANGUAGES);
+        let mut buffer = render(prompt, &mut state);
+        assert!(buffer.get(2, 1).modifier.contains(Modifier::REVERSED));
+        assert!(!buffer.get(2, 2).modifier.contains(Modifier::REVERSED));
+        buffer.set_style(buffer.area, Style::reset());
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "? Language ›        ",
+                "  rust              ",
+                "  ruby              ",
+                "  python            ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_highlights_matches() {
+        let theme = Theme::new();
+        let prompt = AutocompletePrompt::from("Language").with_theme(theme.clone());
+        let mut state = AutocompleteState::new(LANGUAGES).with_value("ty");
+        let buffer = render(prompt, &mut state);
+        // `typescript` is the best match
+        assert_eq!(buffer.get(2, 1).symbol(), "t");
+        assert_eq!(buffer.get(2, 1).fg, theme.highlight.fg.unwrap());
+        assert_eq!(buffer.get(3, 1).fg, theme.highlight.fg.unwrap());
+        assert_ne!(buffer.get(4, 1).fg, theme.highlight.fg.unwrap());
+    }
+
+    #[test]
+    fn render_scrolls_to_highlight() {
+        let prompt = AutocompletePrompt::from("Language")
+            .with_max_visible(2)
+            .with_theme(Theme::new().no_color());
+        let mut state = AutocompleteState::new(LANGUAGES);
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Down.into());
+        let mut buffer = render(prompt, &mut state);
+        buffer.set_style(buffer.area, Style::reset());
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "? Language ›        ",
+                "  ruby              ",
+                "  python            ",
+                "                    ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_done() {
+        let prompt = AutocompletePrompt::from("Language").with_theme(Theme::new().no_color());
+        let mut state = AutocompleteState::new(LANGUAGES).with_value("py");
+        state.handle_key_event(KeyCode::Enter.into());
+        let mut buffer = render(prompt, &mut state);
+        buffer.set_style(buffer.area, Style::reset());
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "✔ Language › python ",
+                "                    ",
+                "                    ",
+                "                    ",
+            ])
+        );
+    }
+}
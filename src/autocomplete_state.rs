@@ -0,0 +1,359 @@
+use std::borrow::Cow;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::{fuzzy_filter, prelude::*, FuzzyMatch, State};
+
+/// The state of an [`AutocompletePrompt`].
+///
+/// Typing filters the suggestions using fuzzy matching (see [`fuzzy_filter`]). The best matches
+/// are listed first.
+///
+/// Keybindings in addition to those of [`TextState`]:
+/// - Up | Ctrl+P: Highlight the previous suggestion
+/// - Down | Ctrl+N: Highlight the next suggestion
+/// - Enter: Accept the highlighted suggestion, or the typed text if free text is allowed
+///
+/// Unless free text is allowed, the best match is highlighted as the user types, and the prompt
+/// only completes with a suggestion.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AutocompleteState<'a> {
+    status: Status,
+    focus: FocusState,
+    input: TextState<'a>,
+    suggestions: Vec<Cow<'a, str>>,
+    matches: Vec<FuzzyMatch>,
+    highlighted: Option<usize>,
+    allow_free_text: bool,
+}
+
+impl<'a> AutocompleteState<'a> {
+    #[must_use]
+    pub fn new<I, T>(suggestions: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        let mut state = Self {
+            suggestions: suggestions.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        };
+        state.update_matches();
+        state
+    }
+
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_focus(mut self, focus: FocusState) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    /// Sets the typed text, filtering the suggestions.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<Cow<'a, str>>) -> Self {
+        self.input = self.input.with_value(value);
+        self.input.move_end();
+        self.update_matches();
+        self
+    }
+
+    /// Allows completing the prompt with text that does not match a suggestion.
+    ///
+    /// When free text is allowed, no suggestion is highlighted until Up or Down is pressed.
+    #[must_use]
+    pub fn with_free_text(mut self, allow_free_text: bool) -> Self {
+        self.allow_free_text = allow_free_text;
+        self.update_matches();
+        self
+    }
+
+    /// Replaces the suggestions, filtering them by the typed text.
+    pub fn set_suggestions<I, T>(&mut self, suggestions: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.suggestions = suggestions.into_iter().map(Into::into).collect();
+        self.update_matches();
+    }
+
+    /// All suggestions, in the order they were provided.
+    #[must_use]
+    pub fn suggestions(&self) -> &[Cow<'a, str>] {
+        &self.suggestions
+    }
+
+    /// The suggestions that match the typed text, from best to worst.
+    ///
+    /// [`FuzzyMatch::index`] is the index of the suggestion in [`AutocompleteState::suggestions`].
+    #[must_use]
+    pub fn matches(&self) -> &[FuzzyMatch] {
+        &self.matches
+    }
+
+    /// The index of the highlighted match in [`AutocompleteState::matches`], if any.
+    #[must_use]
+    pub const fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// The highlighted suggestion, if any.
+    #[must_use]
+    pub fn highlighted_suggestion(&self) -> Option<&str> {
+        let index = self.matches.get(self.highlighted?)?.index;
+        Some(&self.suggestions[index])
+    }
+
+    /// The state of the text input.
+    #[must_use]
+    pub const fn input(&self) -> &TextState<'a> {
+        &self.input
+    }
+
+    /// A mutable reference to the state of the text input.
+    pub fn input_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.input
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    /// Highlights the next match, wrapping around to the first.
+    pub fn highlight_next(&mut self) {
+        let len = self.matches.len();
+        if len == 0 {
+            return;
+        }
+        self.highlighted = Some(self.highlighted.map_or(0, |index| (index + 1) % len));
+    }
+
+    /// Highlights the previous match, wrapping around to the last.
+    pub fn highlight_previous(&mut self) {
+        let len = self.matches.len();
+        if len == 0 {
+            return;
+        }
+        self.highlighted = Some(
+            self.highlighted
+                .map_or(len - 1, |index| (index + len - 1) % len),
+        );
+    }
+
+    fn update_matches(&mut self) {
+        let suggestions = self.suggestions.iter().map(AsRef::as_ref);
+        self.matches = fuzzy_filter(self.input.value(), suggestions);
+        self.highlighted = if self.allow_free_text || self.matches.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+    }
+}
+
+impl State for AutocompleteState<'_> {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn status_mut(&mut self) -> &mut Status {
+        &mut self.status
+    }
+
+    fn focus_state_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    fn focus_state(&self) -> FocusState {
+        self.focus
+    }
+
+    fn position(&self) -> usize {
+        self.input.position()
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        self.input.position_mut()
+    }
+
+    fn cursor(&self) -> (u16, u16) {
+        self.input.cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut (u16, u16) {
+        self.input.cursor_mut()
+    }
+
+    /// The typed text, or the accepted suggestion once the prompt is done.
+    fn value(&self) -> &str {
+        self.input.value()
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        self.input.value_mut()
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Enter, _) => self.complete(),
+            (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                self.highlight_previous();
+            }
+            (KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                self.highlight_next();
+            }
+            _ => {
+                let value = self.input.value().to_string();
+                self.input.handle_key_event(key_event);
+                if self.input.status().is_aborted() {
+                    self.status = Status::Aborted;
+                }
+                if self.input.value() != value {
+                    self.update_matches();
+                }
+            }
+        }
+    }
+
+    fn complete(&mut self) {
+        if let Some(suggestion) = self.highlighted_suggestion() {
+            let suggestion = suggestion.to_string();
+            *self.input.value_mut() = suggestion;
+            self.input.move_end();
+        } else if !self.allow_free_text {
+            return;
+        }
+        self.input.complete();
+        self.status = Status::Done;
+    }
+
+    fn abort(&mut self) {
+        self.input.abort();
+        self.status = Status::Aborted;
+    }
+
+    fn delete(&mut self) {
+        self.input.delete();
+        self.update_matches();
+    }
+
+    fn backspace(&mut self) {
+        self.input.backspace();
+        self.update_matches();
+    }
+
+    fn kill(&mut self) {
+        self.input.kill();
+        self.update_matches();
+    }
+
+    fn truncate(&mut self) {
+        self.input.truncate();
+        self.update_matches();
+    }
+
+    fn push(&mut self, c: char) {
+        self.input.push(c);
+        self.update_matches();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::type_str;
+
+    const LANGUAGES: [&str; 4] = ["rust", "ruby", "python", "typescript"];
+
+    fn matched(state: &AutocompleteState) -> Vec<String> {
+        state
+            .matches()
+            .iter()
+            .map(|m| state.suggestions()[m.index].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn new() {
+        let state = AutocompleteState::new(LANGUAGES);
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(matched(&state), LANGUAGES);
+        assert_eq!(state.highlighted(), Some(0));
+    }
+
+    #[test]
+    fn filter() {
+        let mut state = AutocompleteState::new(LANGUAGES);
+        type_str(&mut state, "ty");
+        assert_eq!(matched(&state), ["typescript"]);
+        assert_eq!(state.highlighted_suggestion(), Some("typescript"));
+        state.handle_key_event(KeyCode::Backspace.into());
+        assert_eq!(matched(&state), ["typescript", "python", "rust"]);
+        state.handle_key_event(KeyCode::Backspace.into());
+        assert_eq!(matched(&state), LANGUAGES);
+    }
+
+    #[test]
+    fn highlight() {
+        let mut state = AutocompleteState::new(LANGUAGES);
+        state.handle_key_event(KeyCode::Down.into());
+        assert_eq!(state.highlighted_suggestion(), Some("ruby"));
+        state.handle_key_event(KeyCode::Up.into());
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.highlighted_suggestion(), Some("typescript"));
+    }
+
+    #[test]
+    fn accept_suggestion() {
+        let mut state = AutocompleteState::new(LANGUAGES);
+        type_str(&mut state, "rb");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.value(), "ruby");
+    }
+
+    #[test]
+    fn reject_free_text() {
+        let mut state = AutocompleteState::new(LANGUAGES);
+        type_str(&mut state, "go");
+        assert!(state.matches().is_empty());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+    }
+
+    #[test]
+    fn accept_free_text() {
+        let mut state = AutocompleteState::new(LANGUAGES).with_free_text(true);
+        type_str(&mut state, "ru");
+        assert_eq!(state.highlighted(), None);
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.value(), "ru");
+    }
+
+    #[test]
+    fn free_text_accepts_highlighted() {
+        let mut state = AutocompleteState::new(LANGUAGES).with_free_text(true);
+        type_str(&mut state, "ru");
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.value(), "rust");
+    }
+
+    #[test]
+    fn abort() {
+        let mut state = AutocompleteState::new(LANGUAGES);
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+    }
+}
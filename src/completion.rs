@@ -0,0 +1,104 @@
+/// Provides candidate completions for the value of a prompt.
+///
+/// Set a completer with [`TextState::with_completer`]. Pressing Tab completes the common prefix of
+/// the candidates, and pressing Tab again cycles through them while they are listed below the
+/// input.
+///
+/// Closures of the form `Fn(&str, usize) -> Vec<String>` are completers.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_prompts::prelude::*;
+///
+/// let colors = ["red", "green", "blue"];
+/// let state = TextState::new().with_completer(move |value: &str, _position: usize| {
+///     colors
+///         .iter()
+///         .filter(|color| color.starts_with(value))
+///         .map(ToString::to_string)
+///         .collect()
+/// });
+/// ```
+///
+/// [`TextState::with_completer`]: crate::TextState::with_completer
+pub trait Completer {
+    /// Returns the candidate completions for the value with the cursor at the given character
+    /// position.
+    ///
+    /// Each candidate replaces the part of the value before the cursor, so candidates usually start
+    /// with that part.
+    fn complete(&self, value: &str, position: usize) -> Vec<String>;
+}
+
+impl<F> Completer for F
+where
+    F: Fn(&str, usize) -> Vec<String>,
+{
+    fn complete(&self, value: &str, position: usize) -> Vec<String> {
+        self(value, position)
+    }
+}
+
+/// The longest prefix shared by all candidates.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn common_prefix(candidates: &[String]) -> &str {
+    let Some((first, rest)) = candidates.split_first() else {
+        return "";
+    };
+    let mut end = first.len();
+    for candidate in rest {
+        end = first
+            .char_indices()
+            .zip(candidate.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or_else(
+                || end.min(candidate.len()),
+                |((index, _), _)| index.min(end),
+            );
+    }
+    &first[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn closure_completer() {
+        let completer = |value: &str, position: usize| vec![format!("{value}:{position}")];
+        assert_eq!(completer.complete("abc", 2), ["abc:2"]);
+    }
+
+    #[test]
+    fn common_prefix_empty() {
+        assert_eq!(common_prefix(&[]), "");
+    }
+
+    #[test]
+    fn common_prefix_single() {
+        assert_eq!(common_prefix(&strings(&["main.rs"])), "main.rs");
+    }
+
+    #[test]
+    fn common_prefix_multiple() {
+        let candidates = strings(&["text_prompt.rs", "text_state.rs", "text.rs"]);
+        assert_eq!(common_prefix(&candidates), "text");
+    }
+
+    #[test]
+    fn common_prefix_shorter_candidate() {
+        let candidates = strings(&["status.rs", "stat"]);
+        assert_eq!(common_prefix(&candidates), "stat");
+    }
+
+    #[test]
+    fn common_prefix_multibyte() {
+        let candidates = strings(&["äëï", "äëö"]);
+        assert_eq!(common_prefix(&candidates), "äë");
+    }
+}
@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+
+use crate::prelude::*;
+
+use ratatui::{prelude::*, widgets::StatefulWidget};
+
+/// A prompt widget for a yes / no question.
+///
+/// While pending, the message is followed by a hint of the keys, with the default capitalized, e.g.
+/// `(Y/n)`. Once answered, the answer is rendered as `yes` or `no`. See [`ConfirmState`] for the
+/// behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmPrompt<'a> {
+    /// The message to display before the answer.
+    message: Cow<'a, str>,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
+}
+
+impl<'a> ConfirmPrompt<'a> {
+    #[must_use]
+    pub const fn new(message: Cow<'a, str>) -> Self {
+        Self {
+            message,
+            theme: Theme::new(),
+        }
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// The message, followed by the hint of the keys while the prompt is pending.
+    fn message(&self, state: &ConfirmState) -> Cow<'a, str> {
+        if state.is_finished() {
+            return self.message.clone();
+        }
+        let hint = match state.default_answer() {
+            Some(true) => "(Y/n)",
+            Some(false) => "(y/N)",
+            None => "(y/n)",
+        };
+        Cow::Owned(format!("{} {hint}", self.message))
+    }
+}
+
+impl Prompt for ConfirmPrompt<'_> {
+    /// Draws the prompt widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if state.is_focused() {
+            frame.set_cursor(state.cursor().0, state.cursor().1);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for ConfirmPrompt<'a> {
+    type State = ConfirmState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let prompt = TextPrompt::new(self.message(state)).with_theme(self.theme);
+        prompt.render(area, buf, state.input_mut());
+    }
+}
+
+impl<T> From<T> for ConfirmPrompt<'static>
+where
+    T: Into<Cow<'static, str>>,
+{
+    fn from(message: T) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+
+    fn render<'a>(prompt: ConfirmPrompt<'a>, state: &mut ConfirmState<'a>) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        prompt.render(buffer.area, &mut buffer, state);
+        buffer.set_style(buffer.area, Style::reset());
+        buffer
+    }
+
+    #[test]
+    fn new() {
+        let prompt = ConfirmPrompt::from("Continue?");
+        assert_eq!(prompt.message, "Continue?");
+        assert_eq!(prompt.theme, Theme::default());
+    }
+
+    #[rstest::rstest]
+    #[case::no_default(ConfirmState::new(), "? Continue? (y/n) › ")]
+    #[case::default_yes(ConfirmState::from(true), "? Continue? (Y/n) › ")]
+    #[case::default_no(ConfirmState::from(false), "? Continue? (y/N) › ")]
+    fn render_pending(#[case] mut state: ConfirmState<'static>, #[case] expected: &str) {
+        let prompt = ConfirmPrompt::from("Continue?");
+        assert_eq!(render(prompt, &mut state), Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_done() {
+        let prompt = ConfirmPrompt::from("Continue?");
+        let mut state = ConfirmState::new();
+        state.handle_key_event(KeyCode::Char('y').into());
+        assert_eq!(
+            render(prompt, &mut state),
+            Buffer::with_lines(["✔ Continue? › yes   "])
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::{prelude::*, State};
+
+/// The state of a [`ConfirmPrompt`].
+///
+/// Keybindings:
+/// - y | Y: Answer yes
+/// - n | N: Answer no
+/// - Enter: Answer the default, if any
+/// - Esc | Ctrl+C: Abort
+///
+/// Once answered, the value of the prompt is `yes` or `no`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ConfirmState<'a> {
+    status: Status,
+    focus: FocusState,
+    input: TextState<'a>,
+    default: Option<bool>,
+    answer: Option<bool>,
+}
+
+impl<'a> ConfirmState<'a> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            status: Status::Pending,
+            focus: FocusState::Unfocused,
+            input: TextState::new(),
+            default: None,
+            answer: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_focus(mut self, focus: FocusState) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    /// Sets the answer that Enter selects.
+    #[must_use]
+    pub const fn with_default(mut self, default: bool) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// The answer that Enter selects, if any.
+    #[must_use]
+    pub const fn default_answer(&self) -> Option<bool> {
+        self.default
+    }
+
+    /// The answer, once the prompt is done.
+    #[must_use]
+    pub const fn answer(&self) -> Option<bool> {
+        self.answer
+    }
+
+    /// The state of the text input, which holds `yes` or `no` once answered.
+    #[must_use]
+    pub const fn input(&self) -> &TextState<'a> {
+        &self.input
+    }
+
+    /// A mutable reference to the state of the text input.
+    pub fn input_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.input
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    /// Answers the prompt, completing it.
+    pub fn set_answer(&mut self, answer: bool) {
+        self.answer = Some(answer);
+        *self.input.value_mut() = if answer { "yes" } else { "no" }.to_string();
+        self.input.move_end();
+        self.input.complete();
+        self.status = Status::Done;
+    }
+}
+
+impl From<bool> for ConfirmState<'_> {
+    fn from(default: bool) -> Self {
+        Self::new().with_default(default)
+    }
+}
+
+impl State for ConfirmState<'_> {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn status_mut(&mut self) -> &mut Status {
+        &mut self.status
+    }
+
+    fn focus_state_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    fn focus_state(&self) -> FocusState {
+        self.focus
+    }
+
+    fn position(&self) -> usize {
+        self.input.position()
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        self.input.position_mut()
+    }
+
+    fn cursor(&self) -> (u16, u16) {
+        self.input.cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut (u16, u16) {
+        self.input.cursor_mut()
+    }
+
+    /// `yes` or `no` once answered, otherwise empty.
+    fn value(&self) -> &str {
+        self.input.value()
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        self.input.value_mut()
+    }
+
+    fn has_default(&self) -> bool {
+        self.default.is_some()
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Char('y' | 'Y'), _) => self.set_answer(true),
+            (KeyCode::Char('n' | 'N'), _) => self.set_answer(false),
+            (KeyCode::Enter, _) => self.complete(),
+            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => self.abort(),
+            _ => {}
+        }
+    }
+
+    /// Answers the default, if any.
+    fn complete(&mut self) {
+        if let Some(default) = self.default {
+            self.set_answer(default);
+        }
+    }
+
+    fn abort(&mut self) {
+        self.input.abort();
+        self.status = Status::Aborted;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let state = ConfirmState::new();
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.answer(), None);
+        assert_eq!(state.value(), "");
+    }
+
+    #[rstest::rstest]
+    #[case::yes('y', true, "yes")]
+    #[case::yes_upper('Y', true, "yes")]
+    #[case::no('n', false, "no")]
+    fn answer(#[case] c: char, #[case] expected: bool, #[case] value: &str) {
+        let mut state = ConfirmState::new();
+        state.handle_key_event(KeyCode::Char(c).into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.answer(), Some(expected));
+        assert_eq!(state.value(), value);
+    }
+
+    #[test]
+    fn other_keys_are_ignored() {
+        let mut state = ConfirmState::new();
+        state.handle_key_event(KeyCode::Char('x').into());
+        assert_eq!(state.value(), "");
+        // without a default, Enter does not answer
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+    }
+
+    #[test]
+    fn default() {
+        let mut state = ConfirmState::from(true);
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.answer(), Some(true));
+    }
+
+    #[test]
+    fn abort() {
+        let mut state = ConfirmState::new();
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+        assert_eq!(state.answer(), None);
+    }
+}
@@ -0,0 +1,253 @@
+use std::borrow::Cow;
+
+use time::{Date, Weekday};
+
+use crate::{date_state::days_in_month, prelude::*};
+
+use ratatui::{
+    prelude::*,
+    widgets::{Paragraph, StatefulWidget, Widget},
+};
+
+/// The width of the calendar: seven two character days separated by spaces.
+const CALENDAR_WIDTH: usize = 20;
+
+/// A prompt widget that asks for a date.
+///
+/// The typed date is rendered on the first line. While the prompt is pending, a calendar of the
+/// month of the highlighted date is rendered below it, followed by the error of the last
+/// completion, if any. Dates outside the bounds are rendered in the hint style of the theme. See
+/// [`DateState`] for the behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatePrompt<'a> {
+    /// The message to display before the date.
+    message: Cow<'a, str>,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
+}
+
+impl<'a> DatePrompt<'a> {
+    #[must_use]
+    pub const fn new(message: Cow<'a, str>) -> Self {
+        Self {
+            message,
+            theme: Theme::new(),
+        }
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// The lines of the calendar of the month of the highlighted date.
+    fn calendar(&self, state: &DateState) -> Vec<Line<'static>> {
+        let date = state.date();
+        let title = format!("{} {}", date.month(), date.year());
+        let mut lines = vec![Line::styled(
+            format!("{title:^CALENDAR_WIDTH$}"),
+            self.theme.message,
+        )];
+
+        let mut weekday = state.week_start();
+        let mut weekdays = Vec::new();
+        for _ in 0..7 {
+            weekdays.push(weekday_name(weekday));
+            weekday = weekday.next();
+        }
+        lines.push(Line::styled(weekdays.join(" "), self.theme.hint));
+
+        let first = date.replace_day(1).unwrap_or(date);
+        let offset = (first.weekday().number_days_from_monday() + 7
+            - state.week_start().number_days_from_monday())
+            % 7;
+        let days = days_in_month(date.year(), date.month());
+        let mut week = vec![Span::raw("  "); usize::from(offset)];
+        for day in 1..=days {
+            let Ok(day) = date.replace_day(day) else {
+                continue;
+            };
+            week.push(self.day(state, day));
+            if week.len() == 7 || day.day() == days {
+                lines.push(Line::from(
+                    itertools::intersperse(std::mem::take(&mut week), Span::raw(" "))
+                        .collect::<Vec<_>>(),
+                ));
+            }
+        }
+        lines
+    }
+
+    fn day(&self, state: &DateState, day: Date) -> Span<'static> {
+        let style = if day == state.date() {
+            self.theme.value.reversed()
+        } else if state.is_in_bounds(day) {
+            self.theme.value
+        } else {
+            self.theme.hint
+        };
+        Span::styled(format!("{:>2}", day.day()), style)
+    }
+}
+
+/// The two letter name of the weekday.
+const fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "Mo",
+        Weekday::Tuesday => "Tu",
+        Weekday::Wednesday => "We",
+        Weekday::Thursday => "Th",
+        Weekday::Friday => "Fr",
+        Weekday::Saturday => "Sa",
+        Weekday::Sunday => "Su",
+    }
+}
+
+impl Prompt for DatePrompt<'_> {
+    /// Draws the prompt widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if state.is_focused() {
+            frame.set_cursor(state.cursor().0, state.cursor().1);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for DatePrompt<'a> {
+    type State = DateState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let [input_area, calendar_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+
+        let input_prompt = TextPrompt::new(self.message.clone()).with_theme(self.theme.clone());
+        input_prompt.render(input_area, buf, state.input_mut());
+
+        if state.is_finished() {
+            return;
+        }
+        let mut lines = self.calendar(state);
+        if let Some(error) = state.error() {
+            lines.push(Line::styled(error.to_string(), self.theme.error));
+        }
+        Paragraph::new(lines).render(calendar_area, buf);
+    }
+}
+
+impl<T> From<T> for DatePrompt<'static>
+where
+    T: Into<Cow<'static, str>>,
+{
+    fn from(message: T) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+    use time::macros::date;
+
+    use super::*;
+
+    fn render<'a>(prompt: DatePrompt<'a>, state: &mut DateState<'a>, height: u16) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 22, height));
+        prompt.render(buffer.area, &mut buffer, state);
+        buffer
+    }
+
+    fn unstyled(mut buffer: Buffer) -> Buffer {
+        buffer.set_style(buffer.area, Style::reset());
+        buffer
+    }
+
+    #[test]
+    fn new() {
+        let prompt = DatePrompt::from("Date");
+        assert_eq!(prompt.message, "Date");
+        assert_eq!(prompt.theme, Theme::default());
+    }
+
+    #[test]
+    fn render_calendar() {
+        let prompt = DatePrompt::from("Date");
+        let mut state = DateState::from(date!(2024 - 02 - 14));
+        let buffer = render(prompt, &mut state, 8);
+        // February 14 is on the third row of days, third column
+        assert!(buffer.get(6, 5).modifier.contains(Modifier::REVERSED));
+        assert_eq!(
+            unstyled(buffer),
+            Buffer::with_lines([
+                "? Date ›              ",
+                "   February 2024      ",
+                "Mo Tu We Th Fr Sa Su  ",
+                "          1  2  3  4  ",
+                " 5  6  7  8  9 10 11  ",
+                "12 13 14 15 16 17 18  ",
+                "19 20 21 22 23 24 25  ",
+                "26 27 28 29           ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_week_start() {
+        let prompt = DatePrompt::from("Date");
+        let mut state = DateState::from(date!(2024 - 09 - 01)).with_week_start(Weekday::Sunday);
+        let buffer = unstyled(render(prompt, &mut state, 4));
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "? Date ›              ",
+                "   September 2024     ",
+                "Su Mo Tu We Th Fr Sa  ",
+                " 1  2  3  4  5  6  7  ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_bounds() {
+        let theme = Theme::new();
+        let prompt = DatePrompt::from("Date").with_theme(theme.clone());
+        let mut state = DateState::from(date!(2024 - 02 - 14)).with_min_date(date!(2024 - 02 - 02));
+        let buffer = render(prompt, &mut state, 4);
+        // February 1 is out of bounds, February 2 is not
+        assert_eq!(buffer.get(10, 3).modifier, theme.hint.add_modifier);
+        assert_eq!(buffer.get(13, 3).modifier, theme.value.add_modifier);
+    }
+
+    #[test]
+    fn render_error() {
+        let prompt = DatePrompt::from("Date");
+        let mut state = DateState::from(date!(2024 - 02 - 14)).with_value("nope");
+        state.handle_key_event(KeyCode::Enter.into());
+        let buffer = unstyled(render(prompt, &mut state, 10));
+        let error = (0..22)
+            .map(|x| buffer.get(x, 8).symbol())
+            .collect::<String>();
+        assert_eq!(error, "Invalid date, e.g. 202");
+    }
+
+    #[test]
+    fn render_done() {
+        let prompt = DatePrompt::from("Date");
+        let mut state = DateState::from(date!(2024 - 02 - 14));
+        state.handle_key_event(KeyCode::Enter.into());
+        let buffer = unstyled(render(prompt, &mut state, 3));
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "✔ Date › 2024-02-14   ",
+                "                      ",
+                "                      ",
+            ])
+        );
+    }
+}
@@ -0,0 +1,525 @@
+use std::borrow::Cow;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use time::{
+    format_description::OwnedFormatItem, macros::format_description, util::is_leap_year, Date,
+    Duration, Month, OffsetDateTime, Weekday,
+};
+
+use crate::{prelude::*, State};
+
+/// The state of a [`DatePrompt`].
+///
+/// The date can be typed in any of the formats of the state, or picked from a calendar. The
+/// calendar follows the typed date, and picking a date from the calendar replaces the typed text
+/// with the date in the first format.
+///
+/// Keybindings in addition to the editing keybindings of [`TextState`]:
+/// - Left / Right: Highlight the previous / next day, or move the cursor once text is typed
+/// - Up / Down: Highlight the same day of the previous / next week
+/// - Page Up / Page Down: Highlight the same day of the previous / next month
+/// - Enter: Complete the prompt with the typed date, or the highlighted date if nothing is typed
+///
+/// The prompt only becomes [`Status::Done`] if the typed date is valid and within the bounds.
+/// Otherwise an error is shown until the next key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateState<'a> {
+    status: Status,
+    focus: FocusState,
+    input: TextState<'a>,
+    date: Date,
+    formats: Vec<OwnedFormatItem>,
+    min: Option<Date>,
+    max: Option<Date>,
+    week_start: Weekday,
+    error: Option<String>,
+    /// Whether text has been typed since a date was picked, in which case Left / Right move the
+    /// cursor rather than the highlighted date.
+    typing: bool,
+}
+
+impl Default for DateState<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> DateState<'a> {
+    /// Creates a state that highlights today's date (in UTC).
+    ///
+    /// Dates are typed as `YYYY-MM-DD` by default, see [`DateState::with_formats`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            status: Status::Pending,
+            focus: FocusState::Unfocused,
+            input: TextState::new(),
+            date: OffsetDateTime::now_utc().date(),
+            formats: vec![format_description!("[year]-[month]-[day]").into()],
+            min: None,
+            max: None,
+            week_start: Weekday::Monday,
+            error: None,
+            typing: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_focus(mut self, focus: FocusState) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    /// Sets the typed text, highlighting the date in the calendar if it is valid.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<Cow<'a, str>>) -> Self {
+        self.input = self.input.with_value(value);
+        self.input.move_end();
+        self.typing = !self.input.is_empty();
+        if let Some(date) = self.parse(self.input.value()) {
+            self.date = self.clamp(date);
+        }
+        self
+    }
+
+    /// Sets the date that is highlighted in the calendar.
+    #[must_use]
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = self.clamp(date);
+        self
+    }
+
+    /// Sets the formats that dates can be typed in, e.g. made with
+    /// [`time::macros::format_description`].
+    ///
+    /// The first format is used to display dates picked from the calendar. If no formats are given,
+    /// the formats are left unchanged.
+    #[must_use]
+    pub fn with_formats<I, T>(mut self, formats: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OwnedFormatItem>,
+    {
+        let formats: Vec<_> = formats.into_iter().map(Into::into).collect();
+        if !formats.is_empty() {
+            self.formats = formats;
+        }
+        self
+    }
+
+    /// Sets the earliest date that can be picked.
+    #[must_use]
+    pub fn with_min_date(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self.date = self.clamp(self.date);
+        self
+    }
+
+    /// Sets the latest date that can be picked.
+    #[must_use]
+    pub fn with_max_date(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self.date = self.clamp(self.date);
+        self
+    }
+
+    /// Sets the first day of the week in the calendar.
+    ///
+    /// Defaults to Monday.
+    #[must_use]
+    pub const fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// The highlighted date, which is the picked date once the prompt is done.
+    #[must_use]
+    pub const fn date(&self) -> Date {
+        self.date
+    }
+
+    /// The earliest date that can be picked, if any.
+    #[must_use]
+    pub const fn min_date(&self) -> Option<Date> {
+        self.min
+    }
+
+    /// The latest date that can be picked, if any.
+    #[must_use]
+    pub const fn max_date(&self) -> Option<Date> {
+        self.max
+    }
+
+    /// The first day of the week in the calendar.
+    #[must_use]
+    pub const fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// Whether the date is within the bounds.
+    #[must_use]
+    pub fn is_in_bounds(&self, date: Date) -> bool {
+        self.min.map_or(true, |min| date >= min) && self.max.map_or(true, |max| date <= max)
+    }
+
+    /// A mutable reference to the state of the text input.
+    pub fn input_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.input
+    }
+
+    /// The error of the last completion, if the typed date was invalid.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    /// Highlights the date the given number of days after the highlighted date.
+    pub fn move_days(&mut self, days: i64) {
+        if let Some(date) = self.date.checked_add(Duration::days(days)) {
+            self.pick(date);
+        }
+    }
+
+    /// Highlights the same day the given number of months after the highlighted date.
+    ///
+    /// The day is clamped to the length of the month, e.g. January 31 moves to February 29 in a
+    /// leap year.
+    pub fn move_months(&mut self, months: i32) {
+        if let Some(date) = add_months(self.date, months) {
+            self.pick(date);
+        }
+    }
+
+    /// Formats the date in the first format.
+    #[must_use]
+    pub fn format(&self, date: Date) -> String {
+        date.format(&self.formats[0])
+            .unwrap_or_else(|_| date.to_string())
+    }
+
+    /// Parses the date in the first format that matches.
+    fn parse(&self, value: &str) -> Option<Date> {
+        self.formats
+            .iter()
+            .find_map(|format| Date::parse(value.trim(), format).ok())
+    }
+
+    /// Highlights the date, clamped to the bounds, and replaces the typed text with it.
+    fn pick(&mut self, date: Date) {
+        self.date = self.clamp(date);
+        let value = self.format(self.date);
+        *self.input.value_mut() = value;
+        self.input.move_end();
+        self.typing = false;
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        let date = self.min.map_or(date, |min| date.max(min));
+        self.max.map_or(date, |max| date.min(max))
+    }
+
+    fn validate(&self) -> Result<Date, String> {
+        if self.input.is_empty() {
+            return Ok(self.date);
+        }
+        let date = self
+            .parse(self.input.value())
+            .ok_or_else(|| format!("Invalid date, e.g. {}", self.format(self.date)))?;
+        match (self.min, self.max) {
+            (Some(min), _) if date < min => {
+                Err(format!("Date must be on or after {}", self.format(min)))
+            }
+            (_, Some(max)) if date > max => {
+                Err(format!("Date must be on or before {}", self.format(max)))
+            }
+            _ => Ok(date),
+        }
+    }
+}
+
+impl State for DateState<'_> {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn status_mut(&mut self) -> &mut Status {
+        &mut self.status
+    }
+
+    fn focus_state_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    fn focus_state(&self) -> FocusState {
+        self.focus
+    }
+
+    fn position(&self) -> usize {
+        self.input.position()
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        self.input.position_mut()
+    }
+
+    fn cursor(&self) -> (u16, u16) {
+        self.input.cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut (u16, u16) {
+        self.input.cursor_mut()
+    }
+
+    /// The typed text. See [`DateState::date`] for the picked date.
+    fn value(&self) -> &str {
+        self.input.value()
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        self.input.value_mut()
+    }
+
+    /// An empty input completes the prompt with the highlighted date.
+    fn has_default(&self) -> bool {
+        true
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        self.error = None;
+        match key_event.code {
+            KeyCode::Enter => self.complete(),
+            KeyCode::Left if !self.typing => self.move_days(-1),
+            KeyCode::Right if !self.typing => self.move_days(1),
+            KeyCode::Up => self.move_days(-7),
+            KeyCode::Down => self.move_days(7),
+            KeyCode::PageUp => self.move_months(-1),
+            KeyCode::PageDown => self.move_months(1),
+            _ => {
+                self.input.handle_key_event(key_event);
+                self.typing = !self.input.is_empty();
+                if self.input.status().is_aborted() {
+                    self.status = Status::Aborted;
+                }
+                // the calendar follows the typed date
+                if let Some(date) = self.parse(self.input.value()) {
+                    if self.is_in_bounds(date) {
+                        self.date = date;
+                    }
+                }
+            }
+        }
+    }
+
+    fn complete(&mut self) {
+        match self.validate() {
+            Ok(date) => {
+                self.pick(date);
+                self.input.complete();
+                self.status = Status::Done;
+            }
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    fn abort(&mut self) {
+        self.input.abort();
+        self.status = Status::Aborted;
+    }
+
+    fn delete(&mut self) {
+        self.input.delete();
+    }
+
+    fn backspace(&mut self) {
+        self.input.backspace();
+    }
+
+    fn kill(&mut self) {
+        self.input.kill();
+    }
+
+    fn truncate(&mut self) {
+        self.input.truncate();
+    }
+
+    fn push(&mut self, c: char) {
+        self.input.push(c);
+    }
+}
+
+/// The same day the given number of months after the date, clamped to the length of the month.
+fn add_months(date: Date, months: i32) -> Option<Date> {
+    let index = date.year() * 12 + i32::from(u8::from(date.month())) - 1 + months;
+    let year = index.div_euclid(12);
+    let month = Month::try_from(u8::try_from(index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date.day().min(days_in_month(year, month));
+    Date::from_calendar_date(year, month, day).ok()
+}
+
+/// The number of days in the month of the year.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) const fn days_in_month(year: i32, month: Month) -> u8 {
+    match month {
+        Month::February if is_leap_year(year) => 29,
+        Month::February => 28,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        _ => 31,
+    }
+}
+
+impl From<Date> for DateState<'_> {
+    fn from(date: Date) -> Self {
+        Self::new().with_date(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+    use time::macros::{date, format_description};
+
+    use super::*;
+    use crate::test_utils::type_str;
+
+    #[test]
+    fn new() {
+        let state = DateState::new();
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.date(), OffsetDateTime::now_utc().date());
+        assert_eq!(state.week_start(), Weekday::Monday);
+        assert_eq!(state.value(), "");
+    }
+
+    #[test]
+    fn navigate() {
+        let mut state = DateState::from(date!(2024 - 01 - 31));
+        state.handle_key_event(KeyCode::Right.into());
+        assert_eq!(state.date(), date!(2024 - 02 - 01));
+        assert_eq!(state.value(), "2024-02-01");
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.date(), date!(2024 - 01 - 25));
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Left.into());
+        assert_eq!(state.date(), date!(2024 - 01 - 31));
+    }
+
+    #[test]
+    fn navigate_months() {
+        let mut state = DateState::from(date!(2024 - 01 - 31));
+        state.handle_key_event(KeyCode::PageDown.into());
+        assert_eq!(state.date(), date!(2024 - 02 - 29));
+        state.handle_key_event(KeyCode::PageUp.into());
+        state.handle_key_event(KeyCode::PageUp.into());
+        assert_eq!(state.date(), date!(2023 - 12 - 29));
+    }
+
+    #[test]
+    fn bounds() {
+        let mut state = DateState::from(date!(2024 - 01 - 30))
+            .with_min_date(date!(2024 - 01 - 29))
+            .with_max_date(date!(2024 - 01 - 31));
+        state.handle_key_event(KeyCode::Down.into());
+        assert_eq!(state.date(), date!(2024 - 01 - 31));
+        state.handle_key_event(KeyCode::PageUp.into());
+        assert_eq!(state.date(), date!(2024 - 01 - 29));
+        assert!(!state.is_in_bounds(date!(2024 - 02 - 01)));
+    }
+
+    #[test]
+    fn type_date() {
+        let mut state = DateState::from(date!(2024 - 01 - 31));
+        type_str(&mut state, "2023-06-15");
+        assert_eq!(state.date(), date!(2023 - 06 - 15));
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.date(), date!(2023 - 06 - 15));
+    }
+
+    #[test]
+    fn move_cursor_in_typed_date() {
+        let mut state = DateState::from(date!(2024 - 01 - 31));
+        type_str(&mut state, "2023-06-15");
+        state.handle_key_event(KeyCode::Left.into());
+        state.handle_key_event(KeyCode::Backspace.into());
+        type_str(&mut state, "6");
+        assert_eq!(state.value(), "2023-06-65");
+        assert_eq!(state.date(), date!(2023 - 06 - 15));
+        // the arrows move the highlighted date again once the typed text is cleared
+        for _ in 0..10 {
+            state.handle_key_event(KeyCode::Delete.into());
+            state.handle_key_event(KeyCode::Backspace.into());
+        }
+        state.handle_key_event(KeyCode::Left.into());
+        assert_eq!(state.date(), date!(2023 - 06 - 14));
+    }
+
+    #[test]
+    fn formats() {
+        let mut state = DateState::from(date!(2024 - 01 - 31)).with_formats([
+            format_description!("[day]/[month]/[year]"),
+            format_description!("[year]-[month]-[day]"),
+        ]);
+        type_str(&mut state, "2023-06-15");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.date(), date!(2023 - 06 - 15));
+        assert_eq!(state.value(), "15/06/2023");
+    }
+
+    #[test]
+    fn no_formats() {
+        let mut state =
+            DateState::from(date!(2024 - 01 - 31)).with_formats(Vec::<OwnedFormatItem>::new());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.value(), "2024-01-31");
+    }
+
+    #[test]
+    fn complete_highlighted() {
+        let mut state = DateState::from(date!(2024 - 01 - 31));
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.value(), "2024-01-31");
+    }
+
+    #[test]
+    fn invalid() {
+        let mut state = DateState::from(date!(2024 - 01 - 31));
+        type_str(&mut state, "2024-02-30");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.error(), Some("Invalid date, e.g. 2024-01-31"));
+        state.handle_key_event(KeyCode::Backspace.into());
+        assert_eq!(state.error(), None);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let mut state = DateState::from(date!(2024 - 01 - 31)).with_max_date(date!(2024 - 12 - 31));
+        type_str(&mut state, "2025-01-01");
+        assert_eq!(state.date(), date!(2024 - 01 - 31));
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.error(), Some("Date must be on or before 2024-12-31"));
+    }
+
+    #[test]
+    fn abort() {
+        let mut state = DateState::new();
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+    }
+}
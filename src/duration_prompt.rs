@@ -0,0 +1,136 @@
+use std::borrow::Cow;
+
+use crate::{prelude::*, segment};
+
+use ratatui::{prelude::*, widgets::StatefulWidget};
+
+/// A prompt widget that asks for a duration.
+///
+/// The duration is rendered on the first line, with the focused segment reversed while the prompt
+/// is pending, followed by the error of the typed duration, if any. See [`DurationState`] for the
+/// behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationPrompt<'a> {
+    /// The message to display before the duration.
+    message: Cow<'a, str>,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
+}
+
+impl<'a> DurationPrompt<'a> {
+    #[must_use]
+    pub const fn new(message: Cow<'a, str>) -> Self {
+        Self {
+            message,
+            theme: Theme::new(),
+        }
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Prompt for DurationPrompt<'_> {
+    /// Draws the prompt widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if state.is_focused() {
+            frame.set_cursor(state.cursor().0, state.cursor().1);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for DurationPrompt<'a> {
+    type State = DurationState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let range = state.segment_range();
+        let error = state.error().map(str::to_string);
+        let input_prompt = TextPrompt::new(self.message).with_theme(self.theme.clone());
+        segment::render(
+            input_prompt,
+            &self.theme,
+            area,
+            buf,
+            state.input_mut(),
+            range,
+            error.as_deref(),
+        );
+    }
+}
+
+impl<T> From<T> for DurationPrompt<'static>
+where
+    T: Into<Cow<'static, str>>,
+{
+    fn from(message: T) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+
+    fn render<'a>(prompt: DurationPrompt<'a>, state: &mut DurationState<'a>) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 2));
+        prompt.render(buffer.area, &mut buffer, state);
+        buffer
+    }
+
+    fn unstyled(mut buffer: Buffer) -> Buffer {
+        buffer.set_style(buffer.area, Style::reset());
+        buffer
+    }
+
+    #[test]
+    fn new() {
+        let prompt = DurationPrompt::from("Timeout");
+        assert_eq!(prompt.message, "Timeout");
+        assert_eq!(prompt.theme, Theme::default());
+    }
+
+    #[test]
+    fn render_segment() {
+        let prompt = DurationPrompt::from("Timeout");
+        let mut state = DurationState::from(Duration::from_secs(5400));
+        state.handle_key_event(KeyCode::Right.into());
+        let buffer = render(prompt, &mut state);
+        // the minutes are reversed, the hours and units are not
+        assert!(!buffer.get(12, 0).modifier.contains(Modifier::REVERSED));
+        assert!(buffer.get(14, 0).modifier.contains(Modifier::REVERSED));
+        assert!(buffer.get(15, 0).modifier.contains(Modifier::REVERSED));
+        assert!(!buffer.get(16, 0).modifier.contains(Modifier::REVERSED));
+        assert_eq!(
+            unstyled(buffer),
+            Buffer::with_lines(["? Timeout › 1h30m             ", ""])
+        );
+    }
+
+    #[test]
+    fn render_error() {
+        let prompt = DurationPrompt::from("Timeout");
+        let mut state = DurationState::from(Duration::from_secs(5400));
+        state.handle_key_event(KeyCode::Char('9').into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            unstyled(render(prompt, &mut state)),
+            Buffer::with_lines([
+                "? Timeout › 9                 ",
+                "Invalid duration, e.g. 1h30m"
+            ])
+        );
+    }
+}
@@ -0,0 +1,431 @@
+use std::{ops::Range, time::Duration};
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+use crate::{
+    prelude::*,
+    segment::{self, Segment, SegmentEditor, Segmented},
+    State,
+};
+
+/// The state of a [`DurationPrompt`].
+///
+/// The duration is shown as `1h30m`, or `1h30m00s` with seconds (see
+/// [`DurationState::with_seconds`]), and edited one segment at a time. Typing a character
+/// replaces the duration with the typed text, e.g. `90m`, `1h 30m` or `2h`, which is parsed when
+/// moving to another segment or completing the prompt.
+///
+/// Keybindings in addition to the editing keybindings of [`TextState`]:
+/// - Left / Right: Focus the previous / next segment
+/// - Up / Down: Increment / decrement the focused segment. The minutes and seconds roll over at
+///   60, the hours stop at zero.
+/// - Enter: Complete the prompt
+///
+/// The prompt only becomes [`Status::Done`] if the typed duration is valid. Otherwise an error is
+/// shown until the next key press.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DurationState<'a> {
+    status: Status,
+    focus: FocusState,
+    editor: SegmentEditor<'a>,
+    value: DurationValue,
+}
+
+/// A duration with the segments it is edited in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DurationValue {
+    duration: Duration,
+    seconds: bool,
+}
+
+impl Default for DurationState<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DurationState<'_> {
+    /// Creates a state for a duration in hours and minutes, starting at zero.
+    #[must_use]
+    pub fn new() -> Self {
+        let value = DurationValue {
+            duration: Duration::ZERO,
+            seconds: false,
+        };
+        Self {
+            status: Status::Pending,
+            focus: FocusState::Unfocused,
+            editor: SegmentEditor::new(&value),
+            value,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_focus(mut self, focus: FocusState) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    /// Sets the duration.
+    ///
+    /// The duration is truncated to whole minutes, or whole seconds with seconds.
+    #[must_use]
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.value.duration = duration;
+        self.value.truncate();
+        self.editor.sync(&self.value);
+        self
+    }
+
+    /// Sets whether the seconds are edited as well.
+    #[must_use]
+    pub fn with_seconds(mut self, seconds: bool) -> Self {
+        self.value.seconds = seconds;
+        self.value.truncate();
+        self.editor.sync(&self.value);
+        self
+    }
+
+    /// The duration, which is the entered duration once the prompt is done.
+    ///
+    /// Typed text only changes the duration once it is parsed.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.value.duration
+    }
+
+    /// Whether the seconds are edited as well.
+    #[must_use]
+    pub const fn is_seconds(&self) -> bool {
+        self.value.seconds
+    }
+
+    /// The index of the focused segment.
+    #[must_use]
+    pub const fn segment(&self) -> usize {
+        self.editor.segment()
+    }
+
+    /// Whether the duration is being typed rather than edited segment by segment.
+    #[must_use]
+    pub const fn is_typing(&self) -> bool {
+        self.editor.is_typing()
+    }
+
+    /// The character range of the focused segment in the value, unless the duration is being
+    /// typed.
+    #[must_use]
+    pub fn segment_range(&self) -> Option<Range<usize>> {
+        self.editor.segment_range(&self.value)
+    }
+
+    /// The error of the typed duration, if it was invalid.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.editor.error()
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+}
+
+impl<'a> DurationState<'a> {
+    /// A mutable reference to the state of the text input.
+    pub fn input_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.editor.input
+    }
+}
+
+impl DurationValue {
+    /// Drops the parts of the duration that are not edited.
+    fn truncate(&mut self) {
+        let unit = if self.seconds { 1 } else { 60 };
+        self.duration = Duration::from_secs(self.duration.as_secs() / unit * unit);
+    }
+}
+
+impl Segmented for DurationValue {
+    fn segments(&self) -> Vec<Segment> {
+        let secs = self.duration.as_secs();
+        let mut segments = vec![
+            Segment::new((secs / 3600).to_string(), "h"),
+            Segment::new(format!("{:02}", secs / 60 % 60), "m"),
+        ];
+        if self.seconds {
+            segments.push(Segment::new(format!("{:02}", secs % 60), "s"));
+        }
+        segments
+    }
+
+    fn step(&mut self, segment: usize, delta: i64) {
+        let secs = self.duration.as_secs();
+        let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+        let roll = |value: u64| {
+            let value = i64::try_from(value).unwrap_or(0) + delta;
+            u64::try_from(value.rem_euclid(60)).unwrap_or(0)
+        };
+        let (hours, minutes, seconds) = match segment {
+            0 => (hours.saturating_add_signed(delta), minutes, seconds),
+            1 => (hours, roll(minutes), seconds),
+            _ => (hours, minutes, roll(seconds)),
+        };
+        // a step beyond the longest duration is ignored
+        if let Some(secs) = hours
+            .checked_mul(3600)
+            .and_then(|secs| secs.checked_add(minutes * 60 + seconds))
+        {
+            self.duration = Duration::from_secs(secs);
+        }
+    }
+
+    fn parse(&mut self, text: &str) -> Result<(), String> {
+        self.duration = parse_duration(text, self.seconds)
+            .ok_or_else(|| format!("Invalid duration, e.g. {}", segment::join(&self.segments())))?;
+        Ok(())
+    }
+}
+
+/// Parses numbers followed by the units `h`, `m` and (with seconds) `s`, e.g. `1h30m` or `90m`.
+///
+/// Each unit can appear at most once, in that order.
+fn parse_duration(text: &str, seconds: bool) -> Option<Duration> {
+    let units: &[(char, u64)] = if seconds {
+        &[('h', 3600), ('m', 60), ('s', 1)]
+    } else {
+        &[('h', 3600), ('m', 60)]
+    };
+    let mut rest = text.trim().to_ascii_lowercase();
+    rest.retain(|c| !c.is_whitespace());
+    if rest.is_empty() {
+        return None;
+    }
+    let mut units = units.iter();
+    let mut secs = 0u64;
+    let mut rest = rest.as_str();
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let number: u64 = rest[..digits].parse().ok()?;
+        let unit = rest[digits..].chars().next()?;
+        let (_, factor) = units.find(|(name, _)| *name == unit)?;
+        secs = secs.checked_add(number.checked_mul(*factor)?)?;
+        rest = &rest[digits + unit.len_utf8()..];
+    }
+    Some(Duration::from_secs(secs))
+}
+
+impl State for DurationState<'_> {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn status_mut(&mut self) -> &mut Status {
+        &mut self.status
+    }
+
+    fn focus_state_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    fn focus_state(&self) -> FocusState {
+        self.focus
+    }
+
+    fn position(&self) -> usize {
+        self.editor.input.position()
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        self.editor.input.position_mut()
+    }
+
+    fn cursor(&self) -> (u16, u16) {
+        self.editor.input.cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut (u16, u16) {
+        self.editor.input.cursor_mut()
+    }
+
+    /// The formatted or typed duration. See [`DurationState::duration`] for the entered duration.
+    fn value(&self) -> &str {
+        self.editor.input.value()
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        self.editor.input.value_mut()
+    }
+
+    /// The prompt always holds a duration, which starts at its initial value.
+    fn has_default(&self) -> bool {
+        true
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        if key_event.code == KeyCode::Enter {
+            self.complete();
+            return;
+        }
+        self.editor.handle_key_event(key_event, &mut self.value);
+        if self.editor.input.status().is_aborted() {
+            self.status = Status::Aborted;
+        }
+    }
+
+    fn complete(&mut self) {
+        if self.editor.commit(&mut self.value) {
+            self.editor.input.complete();
+            self.status = Status::Done;
+        }
+    }
+
+    fn abort(&mut self) {
+        self.editor.input.abort();
+        self.status = Status::Aborted;
+    }
+
+    fn delete(&mut self) {
+        self.editor.input.delete();
+    }
+
+    fn backspace(&mut self) {
+        self.editor.input.backspace();
+    }
+
+    fn kill(&mut self) {
+        self.editor.input.kill();
+    }
+
+    fn truncate(&mut self) {
+        self.editor.input.truncate();
+    }
+
+    fn push(&mut self, c: char) {
+        self.editor.input.push(c);
+    }
+}
+
+impl From<Duration> for DurationState<'_> {
+    fn from(duration: Duration) -> Self {
+        Self::new().with_duration(duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+    use crate::test_utils::type_str;
+
+    const fn minutes(minutes: u64) -> Duration {
+        Duration::from_secs(minutes * 60)
+    }
+
+    #[test]
+    fn new() {
+        let state = DurationState::new();
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.duration(), Duration::ZERO);
+        assert_eq!(state.value(), "0h00m");
+        assert_eq!(state.segment_range(), Some(0..1));
+    }
+
+    #[test]
+    fn with_duration() {
+        let state = DurationState::from(Duration::from_secs(5430));
+        assert_eq!(state.duration(), minutes(90));
+        assert_eq!(state.value(), "1h30m");
+        let state = state
+            .with_seconds(true)
+            .with_duration(Duration::from_secs(5430));
+        assert_eq!(state.value(), "1h30m30s");
+    }
+
+    #[test]
+    fn step() {
+        let mut state = DurationState::from(minutes(59));
+        state.handle_key_event(KeyCode::Down.into());
+        assert_eq!(state.duration(), minutes(59));
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.value(), "1h59m");
+        state.handle_key_event(KeyCode::Right.into());
+        assert_eq!(state.segment_range(), Some(2..4));
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.duration(), minutes(60));
+        state.handle_key_event(KeyCode::Down.into());
+        assert_eq!(state.value(), "1h59m");
+    }
+
+    #[test]
+    fn step_longest_duration() {
+        let max = Duration::from_secs(u64::MAX);
+        let mut state = DurationState::new().with_seconds(true).with_duration(max);
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.duration(), max);
+        state.handle_key_event(KeyCode::Right.into());
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.duration(), max);
+        state.handle_key_event(KeyCode::Left.into());
+        state.handle_key_event(KeyCode::Down.into());
+        assert_eq!(state.duration(), Duration::from_secs(u64::MAX - 3600));
+    }
+
+    #[test]
+    fn type_duration() {
+        let mut state = DurationState::new();
+        type_str(&mut state, "90m");
+        assert!(state.is_typing());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.duration(), minutes(90));
+        assert_eq!(state.value(), "1h30m");
+    }
+
+    #[rstest::rstest]
+    #[case::hours_and_minutes("1h30m", 5400)]
+    #[case::spaces(" 1h 30m ", 5400)]
+    #[case::upper_case("2H", 7200)]
+    #[case::seconds("1m30s", 90)]
+    fn parse(#[case] value: &str, #[case] secs: u64) {
+        assert_eq!(parse_duration(value, true), Some(Duration::from_secs(secs)));
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(parse_duration(" ", true), None);
+    }
+
+    #[rstest::rstest]
+    #[case::no_unit("90")]
+    #[case::unknown_unit("3d")]
+    #[case::repeated_unit("1h2h")]
+    #[case::out_of_order("30m1h")]
+    #[case::no_number("h")]
+    #[case::seconds_disabled("30s")]
+    fn invalid(#[case] value: &str) {
+        let mut state = DurationState::from(minutes(90));
+        type_str(&mut state, value);
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.error(), Some("Invalid duration, e.g. 1h30m"));
+        assert_eq!(state.duration(), minutes(90));
+    }
+
+    #[test]
+    fn abort() {
+        let mut state = DurationState::new();
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+    }
+}
@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+
+use itertools::Itertools;
+
+use crate::prelude::*;
+
+use ratatui::{
+    prelude::*,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A prompt widget that lets the user pick files by navigating directories.
+///
+/// The filter is rendered on the first line, the listed directory on the second line, and the
+/// entries below it, scrolled so that the highlighted entry is visible. Directories are rendered
+/// with a trailing `/`, and in multi-select mode each file is prefixed with a checkbox. Once the
+/// prompt is done, only the first line is rendered, with the names of the picked files. See
+/// [`FilePickerState`] for the behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePickerPrompt<'a> {
+    /// The message to display before the filter.
+    message: Cow<'a, str>,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
+}
+
+impl<'a> FilePickerPrompt<'a> {
+    #[must_use]
+    pub const fn new(message: Cow<'a, str>) -> Self {
+        Self {
+            message,
+            theme: Theme::new(),
+        }
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// The lines of the listed entries, scrolled so that the highlighted entry is visible.
+    fn entries(&self, state: &FilePickerState, height: usize) -> Vec<Line<'static>> {
+        let offset = (state.highlighted() + 1).saturating_sub(height);
+        state
+            .matches()
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(height)
+            .map(|(index, m)| {
+                let entry = &state.entries()[m.index];
+                let mut spans = vec![Span::raw("  ")];
+                if state.is_multi_select() && !entry.is_dir {
+                    let checkbox = if state.is_selected(entry) {
+                        "[x] "
+                    } else {
+                        "[ ] "
+                    };
+                    spans.push(Span::raw(checkbox));
+                }
+                spans.extend(self.theme.highlight_matches(&entry.name, &m.positions));
+                if entry.is_dir {
+                    spans.push(Span::styled("/", self.theme.value));
+                }
+                let line = Line::from(spans);
+                if index == state.highlighted() {
+                    line.patch_style(Style::new().reversed())
+                } else {
+                    line
+                }
+            })
+            .collect()
+    }
+}
+
+impl Prompt for FilePickerPrompt<'_> {
+    /// Draws the prompt widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if state.is_focused() {
+            frame.set_cursor(state.cursor().0, state.cursor().1);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for FilePickerPrompt<'a> {
+    type State = FilePickerState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let [input_area, dir_area, entries_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .areas(area);
+
+        let input_prompt = TextPrompt::new(self.message.clone()).with_theme(self.theme.clone());
+        if state.status().is_done() {
+            let names = state
+                .selected()
+                .iter()
+                .filter_map(|path| path.file_name())
+                .map(|name| name.to_string_lossy())
+                .join(", ");
+            let mut done = TextState::new().with_status(Status::Done).with_value(names);
+            input_prompt.render(input_area, buf, &mut done);
+            return;
+        }
+        input_prompt.render(input_area, buf, state.filter_mut());
+        if state.is_finished() {
+            return;
+        }
+
+        let dir = state.dir().display().to_string();
+        Span::styled(dir, self.theme.hint).render(dir_area, buf);
+        if let Some(error) = state.error() {
+            Span::styled(error.to_string(), self.theme.error).render(entries_area, buf);
+            return;
+        }
+        let entries = self.entries(state, entries_area.height as usize);
+        Text::from(entries).render(entries_area, buf);
+    }
+}
+
+impl<T> From<T> for FilePickerPrompt<'static>
+where
+    T: Into<Cow<'static, str>>,
+{
+    fn from(message: T) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+    use crate::test_utils::temp_dir;
+
+    fn render<'a>(prompt: FilePickerPrompt<'a>, state: &mut FilePickerState<'a>) -> Vec<String> {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 80, 4));
+        prompt.render(buffer.area, &mut buffer, state);
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer.get(x, y).symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn new() {
+        let prompt = FilePickerPrompt::from("File");
+        assert_eq!(prompt.message, "File");
+        assert_eq!(prompt.theme, Theme::default());
+    }
+
+    #[test]
+    fn render_pending() {
+        let dir = temp_dir();
+        let prompt = FilePickerPrompt::from("File");
+        let mut state = FilePickerState::new(dir.path());
+        let dir = state.dir().display().to_string();
+        assert_eq!(
+            render(prompt, &mut state),
+            ["? File ›", &dir, "  src/", "  Cargo.toml"]
+        );
+    }
+
+    #[test]
+    fn render_highlight() {
+        let dir = temp_dir();
+        let prompt = FilePickerPrompt::from("File");
+        let mut state = FilePickerState::new(dir.path());
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Down.into());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 80, 4));
+        prompt.render(buffer.area, &mut buffer, &mut state);
+        // scrolled so that README.md is visible
+        assert_eq!(buffer.get(2, 3).symbol(), "R");
+        assert!(buffer.get(2, 3).modifier.contains(Modifier::REVERSED));
+        assert!(!buffer.get(2, 2).modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn render_multi_select() {
+        let dir = temp_dir();
+        let prompt = FilePickerPrompt::from("File");
+        let mut state = FilePickerState::new(dir.path()).with_multi_select(true);
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Tab.into());
+        let lines = render(prompt, &mut state);
+        assert_eq!(lines[2..], ["  src/", "  [x] Cargo.toml"]);
+    }
+
+    #[test]
+    fn render_done() {
+        let dir = temp_dir();
+        let prompt = FilePickerPrompt::from("File");
+        let mut state = FilePickerState::new(dir.path());
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            render(prompt, &mut state),
+            ["✔ File › Cargo.toml", "", "", ""]
+        );
+    }
+
+    #[test]
+    fn render_error() {
+        let dir = temp_dir();
+        let prompt = FilePickerPrompt::from("File");
+        let mut state = FilePickerState::new(dir.path().join("missing"));
+        let lines = render(prompt, &mut state);
+        assert!(lines[2].starts_with("No such file or directory"));
+    }
+}
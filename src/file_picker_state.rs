@@ -0,0 +1,603 @@
+use std::{
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::{fuzzy_filter, prelude::*, FuzzyMatch, State};
+
+/// An entry of the directory listed by a [`FilePickerState`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FileEntry {
+    /// The file name of the entry.
+    pub name: String,
+    /// Whether the entry is a directory, or a link to one.
+    pub is_dir: bool,
+}
+
+/// The state of a [`FilePickerPrompt`].
+///
+/// Lists the entries of a directory, directories first. Typing filters the entries using fuzzy
+/// matching (see [`fuzzy_filter`]).
+///
+/// Keybindings in addition to the editing keybindings of [`TextState`] for the filter:
+/// - Up | Ctrl+P: Highlight the previous entry
+/// - Down | Ctrl+N: Highlight the next entry
+/// - Enter: Open the highlighted directory, or pick the highlighted file
+/// - Backspace: Go up to the parent directory when the filter is empty
+/// - Alt+H: Show / hide hidden entries
+/// - Tab: Select / deselect the highlighted file when multi-select is enabled
+///
+/// When multi-select is enabled, Enter picks the selected files whatever is highlighted, or picks
+/// the highlighted file (or opens the highlighted directory) if none are selected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FilePickerState<'a> {
+    status: Status,
+    focus: FocusState,
+    dir: PathBuf,
+    entries: Vec<FileEntry>,
+    error: Option<String>,
+    filter: TextState<'a>,
+    matches: Vec<FuzzyMatch>,
+    highlighted: usize,
+    show_hidden: bool,
+    extensions: Vec<String>,
+    multi_select: bool,
+    selected: Vec<PathBuf>,
+}
+
+impl<'a> FilePickerState<'a> {
+    /// Creates a picker listing the given directory.
+    ///
+    /// The directory is made absolute, so that its parents can be listed.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        let dir = dir.into();
+        let mut state = Self {
+            status: Status::Pending,
+            focus: FocusState::Unfocused,
+            dir: fs::canonicalize(&dir).unwrap_or(dir),
+            entries: Vec::new(),
+            error: None,
+            filter: TextState::new(),
+            matches: Vec::new(),
+            highlighted: 0,
+            show_hidden: false,
+            extensions: Vec::new(),
+            multi_select: false,
+            selected: Vec::new(),
+        };
+        state.load();
+        state
+    }
+
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_focus(mut self, focus: FocusState) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    /// Shows entries whose names start with a `.`.
+    #[must_use]
+    pub fn with_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self.update_matches();
+        self
+    }
+
+    /// Only lists files with one of the given extensions, e.g. `["rs", "toml"]`.
+    ///
+    /// Directories are always listed. Extensions are compared ignoring case.
+    #[must_use]
+    pub fn with_extensions<I, T>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+        self.update_matches();
+        self
+    }
+
+    /// Allows picking multiple files, which are selected with Tab.
+    #[must_use]
+    pub const fn with_multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// The directory being listed.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// All entries of the directory, directories first.
+    #[must_use]
+    pub fn entries(&self) -> &[FileEntry] {
+        &self.entries
+    }
+
+    /// The entries that are listed, from best to worst match of the filter.
+    ///
+    /// [`FuzzyMatch::index`] is the index of the entry in [`FilePickerState::entries`]. Hidden
+    /// entries and files without one of the extensions are not listed.
+    #[must_use]
+    pub fn matches(&self) -> &[FuzzyMatch] {
+        &self.matches
+    }
+
+    /// The index of the highlighted match in [`FilePickerState::matches`].
+    #[must_use]
+    pub const fn highlighted(&self) -> usize {
+        self.highlighted
+    }
+
+    /// The highlighted entry, if any entries are listed.
+    #[must_use]
+    pub fn highlighted_entry(&self) -> Option<&FileEntry> {
+        let index = self.matches.get(self.highlighted)?.index;
+        Some(&self.entries[index])
+    }
+
+    /// A mutable reference to the state of the filter input.
+    pub fn filter_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.filter
+    }
+
+    /// The error that occurred while reading the directory, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Whether hidden entries are listed.
+    #[must_use]
+    pub const fn is_showing_hidden(&self) -> bool {
+        self.show_hidden
+    }
+
+    /// Whether multiple files can be picked.
+    #[must_use]
+    pub const fn is_multi_select(&self) -> bool {
+        self.multi_select
+    }
+
+    /// The picked files once the prompt is done, or the files selected so far in multi-select
+    /// mode.
+    #[must_use]
+    pub fn selected(&self) -> &[PathBuf] {
+        &self.selected
+    }
+
+    /// Whether the entry of the listed directory is selected.
+    #[must_use]
+    pub fn is_selected(&self, entry: &FileEntry) -> bool {
+        self.selected.contains(&self.dir.join(&entry.name))
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    /// Highlights the next entry, wrapping around to the first.
+    pub fn highlight_next(&mut self) {
+        if !self.matches.is_empty() {
+            self.highlighted = (self.highlighted + 1) % self.matches.len();
+        }
+    }
+
+    /// Highlights the previous entry, wrapping around to the last.
+    pub fn highlight_previous(&mut self) {
+        let len = self.matches.len();
+        if len > 0 {
+            self.highlighted = (self.highlighted + len - 1) % len;
+        }
+    }
+
+    /// Shows or hides hidden entries.
+    pub fn toggle_hidden(&mut self) {
+        self.show_hidden = !self.show_hidden;
+        self.update_matches();
+    }
+
+    /// Selects or deselects the highlighted file in multi-select mode.
+    pub fn toggle_selected(&mut self) {
+        if !self.multi_select {
+            return;
+        }
+        let Some(entry) = self.highlighted_entry().filter(|entry| !entry.is_dir) else {
+            return;
+        };
+        let path = self.dir.join(&entry.name);
+        if let Some(index) = self.selected.iter().position(|p| *p == path) {
+            self.selected.remove(index);
+        } else {
+            self.selected.push(path);
+        }
+    }
+
+    /// Lists the given directory, clearing the filter.
+    pub fn open(&mut self, dir: impl Into<PathBuf>) {
+        self.dir = dir.into();
+        self.filter.truncate();
+        self.load();
+    }
+
+    /// Lists the parent directory and highlights the directory that was listed.
+    pub fn open_parent(&mut self) {
+        let Some(parent) = self.dir.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        let name = self.dir.file_name().map(OsStr::to_os_string);
+        self.open(parent);
+        if let Some(name) = name.and_then(|name| name.into_string().ok()) {
+            self.highlighted = self
+                .matches
+                .iter()
+                .position(|m| self.entries[m.index].name == name)
+                .unwrap_or(0);
+        }
+    }
+
+    fn load(&mut self) {
+        self.error = None;
+        self.entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    // follow symlinks so that links to directories can be opened
+                    let is_dir = entry.path().is_dir();
+                    Some(FileEntry { name, is_dir })
+                })
+                .sorted_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)))
+                .collect(),
+            Err(error) => {
+                self.error = Some(error.to_string());
+                Vec::new()
+            }
+        };
+        self.update_matches();
+    }
+
+    fn is_listed(&self, entry: &FileEntry) -> bool {
+        if !self.show_hidden && entry.name.starts_with('.') {
+            return false;
+        }
+        if entry.is_dir || self.extensions.is_empty() {
+            return true;
+        }
+        Path::new(&entry.name)
+            .extension()
+            .and_then(OsStr::to_str)
+            .is_some_and(|extension| {
+                self.extensions
+                    .iter()
+                    .any(|e| e.eq_ignore_ascii_case(extension))
+            })
+    }
+
+    fn update_matches(&mut self) {
+        let listed = (0..self.entries.len())
+            .filter(|&index| self.is_listed(&self.entries[index]))
+            .collect_vec();
+        let names = listed
+            .iter()
+            .map(|&index| self.entries[index].name.as_str());
+        self.matches = fuzzy_filter(self.filter.value(), names)
+            .into_iter()
+            .map(|m| FuzzyMatch {
+                index: listed[m.index],
+                ..m
+            })
+            .collect();
+        self.highlighted = 0;
+    }
+}
+
+impl State for FilePickerState<'_> {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn status_mut(&mut self) -> &mut Status {
+        &mut self.status
+    }
+
+    fn focus_state_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    fn focus_state(&self) -> FocusState {
+        self.focus
+    }
+
+    fn position(&self) -> usize {
+        self.filter.position()
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        self.filter.position_mut()
+    }
+
+    fn cursor(&self) -> (u16, u16) {
+        self.filter.cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut (u16, u16) {
+        self.filter.cursor_mut()
+    }
+
+    /// The filter typed by the user. See [`FilePickerState::selected`] for the picked files.
+    fn value(&self) -> &str {
+        self.filter.value()
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        self.filter.value_mut()
+    }
+
+    /// The depth of the listed directory, as opening a directory moves the picker to another step.
+    fn step(&self) -> usize {
+        self.dir.components().count()
+    }
+
+    fn captures_key(&self, key_event: KeyEvent) -> bool {
+        key_event.code == KeyCode::Tab && self.multi_select
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Enter, _) => self.complete(),
+            (KeyCode::Up, _) | (KeyCode::Char('p'), KeyModifiers::CONTROL) => {
+                self.highlight_previous();
+            }
+            (KeyCode::Down, _) | (KeyCode::Char('n'), KeyModifiers::CONTROL) => {
+                self.highlight_next();
+            }
+            (KeyCode::Char('h'), KeyModifiers::ALT) => self.toggle_hidden(),
+            (KeyCode::Tab, _) => self.toggle_selected(),
+            (KeyCode::Backspace, _) | (KeyCode::Char('h'), KeyModifiers::CONTROL)
+                if self.filter.is_empty() =>
+            {
+                self.open_parent();
+            }
+            _ => {
+                let filter = self.filter.value().to_string();
+                self.filter.handle_key_event(key_event);
+                if self.filter.status().is_aborted() {
+                    self.status = Status::Aborted;
+                }
+                if self.filter.value() != filter {
+                    self.update_matches();
+                }
+            }
+        }
+    }
+
+    /// Opens the highlighted directory, or picks the highlighted file.
+    fn complete(&mut self) {
+        if self.multi_select && !self.selected.is_empty() {
+            self.filter.complete();
+            self.status = Status::Done;
+            return;
+        }
+        let Some(entry) = self.highlighted_entry() else {
+            return;
+        };
+        let path = self.dir.join(&entry.name);
+        if entry.is_dir {
+            self.open(path);
+            return;
+        }
+        self.selected = vec![path];
+        self.filter.complete();
+        self.status = Status::Done;
+    }
+
+    fn abort(&mut self) {
+        self.filter.abort();
+        self.status = Status::Aborted;
+    }
+
+    fn delete(&mut self) {
+        self.filter.delete();
+        self.update_matches();
+    }
+
+    fn backspace(&mut self) {
+        self.filter.backspace();
+        self.update_matches();
+    }
+
+    fn kill(&mut self) {
+        self.filter.kill();
+        self.update_matches();
+    }
+
+    fn truncate(&mut self) {
+        self.filter.truncate();
+        self.update_matches();
+    }
+
+    fn push(&mut self, c: char) {
+        self.filter.push(c);
+        self.update_matches();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{temp_dir, type_str};
+
+    fn listed(state: &FilePickerState) -> Vec<String> {
+        state
+            .matches()
+            .iter()
+            .map(|m| state.entries()[m.index].name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn new() {
+        let dir = temp_dir();
+        let state = FilePickerState::new(dir.path());
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.dir(), dir.path());
+        assert_eq!(listed(&state), ["src", "Cargo.toml", "README.md"]);
+        assert_eq!(state.error(), None);
+    }
+
+    #[test]
+    fn missing_dir() {
+        let dir = temp_dir();
+        let state = FilePickerState::new(dir.path().join("missing"));
+        assert!(state.entries().is_empty());
+        assert!(state.error().is_some());
+    }
+
+    #[test]
+    fn hidden() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path());
+        state.handle_key_event(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::ALT));
+        assert!(state.is_showing_hidden());
+        assert_eq!(
+            listed(&state),
+            [".git", "src", ".hidden", "Cargo.toml", "README.md"]
+        );
+    }
+
+    #[test]
+    fn extensions() {
+        let dir = temp_dir();
+        let state = FilePickerState::new(dir.path()).with_extensions(["TOML"]);
+        assert_eq!(listed(&state), ["src", "Cargo.toml"]);
+    }
+
+    #[test]
+    fn filter() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path());
+        type_str(&mut state, "rd");
+        assert_eq!(listed(&state), ["README.md"]);
+        state.handle_key_event(KeyCode::Backspace.into());
+        state.handle_key_event(KeyCode::Backspace.into());
+        assert_eq!(listed(&state), ["src", "Cargo.toml", "README.md"]);
+        assert_eq!(state.dir(), dir.path());
+    }
+
+    #[test]
+    fn descend_and_go_up() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path());
+        type_str(&mut state, "sr");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.dir(), dir.path().join("src"));
+        assert_eq!(state.value(), "");
+        assert_eq!(listed(&state), ["lib.rs", "main.rs"]);
+        assert_eq!(state.status(), Status::Pending);
+
+        state.handle_key_event(KeyCode::Backspace.into());
+        assert_eq!(state.dir(), dir.path());
+        assert_eq!(state.highlighted_entry().unwrap().name, "src");
+    }
+
+    #[test]
+    fn pick_file() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path());
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.selected(), [dir.path().join("Cargo.toml")]);
+    }
+
+    #[test]
+    fn highlight_wraps() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path());
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.highlighted_entry().unwrap().name, "README.md");
+        state.handle_key_event(KeyCode::Down.into());
+        assert_eq!(state.highlighted_entry().unwrap().name, "src");
+    }
+
+    #[test]
+    fn multi_select() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path()).with_multi_select(true);
+        state.handle_key_event(KeyCode::Tab.into());
+        assert!(state.selected().is_empty(), "directories are not selected");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.dir(), dir.path().join("src"));
+
+        state.handle_key_event(KeyCode::Tab.into());
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Tab.into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(
+            state.selected(),
+            [
+                dir.path().join("src/lib.rs"),
+                dir.path().join("src/main.rs")
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_select_complete_anywhere() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path()).with_multi_select(true);
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Tab.into());
+        state.handle_key_event(KeyCode::Up.into());
+        assert!(state.highlighted_entry().unwrap().is_dir);
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.selected(), [dir.path().join("Cargo.toml")]);
+
+        let mut state = FilePickerState::new(dir.path()).with_multi_select(true);
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Tab.into());
+        type_str(&mut state, "xyz");
+        assert!(state.highlighted_entry().is_none());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+    }
+
+    #[test]
+    fn multi_select_deselect() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path()).with_multi_select(true);
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Tab.into());
+        assert!(state.is_selected(state.highlighted_entry().unwrap()));
+        state.handle_key_event(KeyCode::Tab.into());
+        assert!(state.selected().is_empty());
+    }
+
+    #[test]
+    fn abort() {
+        let dir = temp_dir();
+        let mut state = FilePickerState::new(dir.path());
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+    }
+}
@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+
+use ratatui::{prelude::*, widgets::StatefulWidget};
+
+use crate::{prelude::*, FormState};
+
+/// A widget that renders the fields of a [`FormState`] below each other.
+///
+/// Each field is rendered in the number of lines set with [`FormField::with_height`], or a single
+/// line once it is finished. Fields that do not fit in the area are not rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Form<'a> {
+    /// The number of empty lines between the fields.
+    spacing: u16,
+    /// The lifetime of the fields of the state.
+    _fields: PhantomData<&'a ()>,
+}
+
+impl Form<'_> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            spacing: 0,
+            _fields: PhantomData,
+        }
+    }
+
+    /// Sets the number of empty lines between the fields.
+    #[must_use]
+    pub const fn with_spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+impl Prompt for Form<'_> {
+    /// Draws the form widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position of the focused field.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if let Some(field) = state.focused().and_then(|index| state.field(index)) {
+            let (x, y) = field.state().cursor();
+            frame.set_cursor(x, y);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for Form<'a> {
+    type State = FormState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let mut y = area.y;
+        for index in 0..state.fields().len() {
+            let Some(field) = state.field_mut(index) else {
+                break;
+            };
+            let height = field.height().min(area.bottom().saturating_sub(y));
+            if height == 0 {
+                break;
+            }
+            field.render(Rect::new(area.x, y, area.width, height), buf);
+            y = y.saturating_add(height).saturating_add(self.spacing);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+    use crate::FormField;
+
+    fn form() -> FormState<'static> {
+        FormState::new()
+            .with_field(
+                FormField::new(
+                    AutocompletePrompt::from("Language").with_max_visible(2),
+                    AutocompleteState::new(["rust", "python"]),
+                )
+                .with_height(3),
+            )
+            .with_field(FormField::new(TextPrompt::from("Name"), TextState::new()))
+    }
+
+    fn render<'a>(form: Form<'a>, state: &mut FormState<'a>) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        form.render(buffer.area, &mut buffer, state);
+        buffer.set_style(buffer.area, Style::reset());
+        buffer
+    }
+
+    #[test]
+    fn render_fields() {
+        let mut state = form();
+        assert_eq!(
+            render(Form::new(), &mut state),
+            Buffer::with_lines([
+                "? Language ›        ",
+                "  rust              ",
+                "  python            ",
+                "? Name ›            ",
+                "                    ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_collapsed() {
+        let mut state = form();
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            render(Form::new().with_spacing(1), &mut state),
+            Buffer::with_lines([
+                "✔ Language › rust   ",
+                "                    ",
+                "? Name ›            ",
+                "                    ",
+                "                    ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_clipped() {
+        let mut state = form();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+        Form::new().render(buffer.area, &mut buffer, &mut state);
+        buffer.set_style(buffer.area, Style::reset());
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["? Language ›        ", "  rust              "])
+        );
+    }
+
+    #[test]
+    fn cursor_follows_focus() {
+        let mut state = form();
+        state.handle_key_event(KeyCode::Tab.into());
+        render(Form::new(), &mut state);
+        assert_eq!(state.fields()[1].state().cursor(), (9, 3));
+    }
+}
@@ -0,0 +1,493 @@
+use std::fmt;
+
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    prelude::*,
+    widgets::StatefulWidget,
+};
+
+use crate::{prelude::*, State};
+
+/// A prompt and its state, rendered as one of the fields of a [`Form`].
+///
+/// The prompt is cloned each time the field is rendered, so it should be cheap to clone.
+pub struct FormField<'a> {
+    field: Box<dyn Field + 'a>,
+    height: u16,
+    required: bool,
+}
+
+/// A prompt and its state with the type of the prompt erased, so that a form can hold prompts of
+/// different types.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) trait Field {
+    fn state(&self) -> &dyn State;
+    fn state_mut(&mut self) -> &mut dyn State;
+    fn render(&mut self, area: Rect, buf: &mut Buffer);
+}
+
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct PromptField<P, S> {
+    pub(crate) prompt: P,
+    pub(crate) state: S,
+}
+
+impl<P, S> Field for PromptField<P, S>
+where
+    P: StatefulWidget<State = S> + Clone,
+    S: State,
+{
+    fn state(&self) -> &dyn State {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut dyn State {
+        &mut self.state
+    }
+
+    fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        self.prompt.clone().render(area, buf, &mut self.state);
+    }
+}
+
+impl<'a> FormField<'a> {
+    /// Creates a required field that is one line high.
+    #[must_use]
+    pub fn new<P, S>(prompt: P, state: S) -> Self
+    where
+        P: Prompt<State = S> + Clone + 'a,
+        S: State + 'a,
+    {
+        Self {
+            field: Box::new(PromptField { prompt, state }),
+            height: 1,
+            required: true,
+        }
+    }
+
+    /// Sets the number of lines the field is rendered in while it is not finished, e.g. to fit
+    /// the calendar of a [`DatePrompt`]. A finished field is rendered in a single line.
+    #[must_use]
+    pub const fn with_height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets whether the field has to be done for the form to be done.
+    ///
+    /// Fields are required by default.
+    #[must_use]
+    pub const fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// The number of lines the field is rendered in.
+    #[must_use]
+    pub fn height(&self) -> u16 {
+        if self.is_finished() {
+            1
+        } else {
+            self.height
+        }
+    }
+
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// The state of the prompt of the field.
+    #[must_use]
+    pub fn state(&self) -> &dyn State {
+        self.field.state()
+    }
+
+    /// A mutable reference to the state of the prompt of the field.
+    pub fn state_mut(&mut self) -> &mut dyn State {
+        self.field.state_mut()
+    }
+
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.state().status().is_finished()
+    }
+
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        self.field.render(area, buf);
+    }
+}
+
+impl fmt::Debug for FormField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormField")
+            .field("status", &self.state().status())
+            .field("value", &self.state().value())
+            .field("height", &self.height)
+            .field("required", &self.required)
+            .finish()
+    }
+}
+
+/// The state of a [`Form`].
+///
+/// Key events are routed to the focused field. When the focused field is done, the focus moves to
+/// the next field that is not finished.
+///
+/// Keybindings in addition to those of the focused field:
+/// - Tab: Focus the next field that is not finished
+/// - Shift+Tab: Focus the previous field that is not finished
+/// - Ctrl+S: Submit the form, leaving pending optional fields unanswered
+///
+/// Fields that use Tab themselves, e.g. a [`TextState`] with a [`Completer`] or a multi-select
+/// [`FilePickerState`], get Tab rather than the form (see [`State::captures_key`]). The focus then
+/// moves on once the field is done.
+///
+/// The form is [`Status::Done`] once no field is pending, or when it is submitted and every required
+/// field is done. Submitting a form with a pending required field focuses that field instead. The
+/// form is [`Status::Aborted`] when the focused field is aborted.
+#[derive(Debug, Default)]
+pub struct FormState<'a> {
+    status: Status,
+    fields: Vec<FormField<'a>>,
+    focused: usize,
+}
+
+impl<'a> FormState<'a> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            status: Status::Pending,
+            fields: Vec::new(),
+            focused: 0,
+        }
+    }
+
+    /// Adds a field to the end of the form.
+    #[must_use]
+    pub fn with_field(mut self, field: FormField<'a>) -> Self {
+        self.push(field);
+        self
+    }
+
+    /// Adds a field to the end of the form.
+    pub fn push(&mut self, field: FormField<'a>) {
+        self.fields.push(field);
+        self.focus(self.focused);
+    }
+
+    #[must_use]
+    pub const fn status(&self) -> Status {
+        self.status
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    #[must_use]
+    pub fn fields(&self) -> &[FormField<'a>] {
+        &self.fields
+    }
+
+    #[must_use]
+    pub fn field(&self, index: usize) -> Option<&FormField<'a>> {
+        self.fields.get(index)
+    }
+
+    pub fn field_mut(&mut self, index: usize) -> Option<&mut FormField<'a>> {
+        self.fields.get_mut(index)
+    }
+
+    /// The index of the focused field, if the form is pending.
+    #[must_use]
+    pub fn focused(&self) -> Option<usize> {
+        self.fields
+            .get(self.focused)
+            .filter(|field| field.state().is_focused())
+            .map(|_| self.focused)
+    }
+
+    /// Focuses the field at the index, blurring the other fields.
+    pub fn focus(&mut self, index: usize) {
+        self.focused = index.min(self.fields.len().saturating_sub(1));
+        let pending = self.status.is_pending();
+        for (index, field) in self.fields.iter_mut().enumerate() {
+            if pending && index == self.focused {
+                field.state_mut().focus();
+            } else {
+                field.state_mut().blur();
+            }
+        }
+    }
+
+    /// Focuses the next field that is not finished, wrapping around to the first field.
+    pub fn focus_next(&mut self) {
+        if let Some(index) = self.find_pending(true) {
+            self.focus(index);
+        }
+    }
+
+    /// Focuses the previous field that is not finished, wrapping around to the last field.
+    pub fn focus_previous(&mut self) {
+        if let Some(index) = self.find_pending(false) {
+            self.focus(index);
+        }
+    }
+
+    /// The index of the next or previous field after the focused one that is not finished.
+    fn find_pending(&self, forward: bool) -> Option<usize> {
+        let len = self.fields.len();
+        (1..=len)
+            .map(|offset| {
+                if forward {
+                    (self.focused + offset) % len
+                } else {
+                    (self.focused + len - offset) % len
+                }
+            })
+            .find(|&index| !self.fields[index].is_finished())
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release || self.is_finished() {
+            return;
+        }
+        let captured = self
+            .fields
+            .get(self.focused)
+            .is_some_and(|field| field.state().captures_key(key_event));
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Tab, _) if !captured => self.focus_next(),
+            (KeyCode::BackTab, _) if !captured => self.focus_previous(),
+            (KeyCode::Char('s'), KeyModifiers::CONTROL) if !captured => self.submit(),
+            _ => self.update_focused(|state| state.handle_key_event(key_event)),
+        }
+    }
+
+    /// Finishes the form if every required field is done, or focuses the first required field that
+    /// is not.
+    pub fn submit(&mut self) {
+        if !self.status.is_pending() {
+            return;
+        }
+        let pending = self
+            .fields
+            .iter()
+            .position(|field| field.is_required() && !field.state().status().is_done());
+        if let Some(index) = pending {
+            self.focus(index);
+            return;
+        }
+        self.status = Status::Done;
+        self.focus(self.focused);
+    }
+
+    /// Pastes text into the focused field.
+    pub fn handle_paste(&mut self, text: &str) {
+        if !self.is_finished() {
+            self.update_focused(|state| state.handle_paste(text));
+        }
+    }
+
+    /// Passes an event to the focused field and updates the form with the status of the field.
+    fn update_focused(&mut self, handle: impl FnOnce(&mut dyn State)) {
+        let Some(field) = self.fields.get_mut(self.focused) else {
+            return;
+        };
+        handle(field.state_mut());
+        match field.state().status() {
+            Status::Aborted => self.status = Status::Aborted,
+            Status::Done => self.focus_next(),
+            Status::Pending => {}
+        }
+        if self.fields.iter().all(FormField::is_finished) && self.status.is_pending() {
+            self.status = Status::Done;
+        }
+        if self.is_finished() {
+            self.focus(self.focused);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+    use crate::test_utils::type_str;
+
+    fn form() -> FormState<'static> {
+        FormState::new()
+            .with_field(FormField::new(TextPrompt::from("Name"), TextState::new()))
+            .with_field(
+                FormField::new(TextPrompt::from("Nickname"), TextState::new()).with_required(false),
+            )
+            .with_field(FormField::new(TextPrompt::from("Email"), TextState::new()))
+    }
+
+    #[test]
+    fn new() {
+        let form = form();
+        assert_eq!(form.status(), Status::Pending);
+        assert_eq!(form.fields().len(), 3);
+        assert_eq!(form.focused(), Some(0));
+        assert!(form.fields()[0].state().is_focused());
+        assert!(!form.fields()[1].state().is_focused());
+    }
+
+    #[test]
+    fn routes_keys_to_focused_field() {
+        let mut form = form();
+        type_str(&mut form, "Ada");
+        form.handle_key_event(KeyCode::Tab.into());
+        type_str(&mut form, "ada");
+        assert_eq!(form.fields()[0].state().value(), "Ada");
+        assert_eq!(form.fields()[1].state().value(), "ada");
+    }
+
+    #[test]
+    fn pastes_into_focused_field() {
+        let mut form = form();
+        form.handle_key_event(KeyCode::Tab.into());
+        form.handle_paste("ada");
+        assert_eq!(form.fields()[0].state().value(), "");
+        assert_eq!(form.fields()[1].state().value(), "ada");
+    }
+
+    #[test]
+    fn tab_wraps_around() {
+        let mut form = form();
+        form.handle_key_event(KeyCode::BackTab.into());
+        assert_eq!(form.focused(), Some(2));
+        form.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(form.focused(), Some(0));
+        assert!(!form.fields()[2].state().is_focused());
+    }
+
+    #[test]
+    fn tab_completes_in_field_with_completer() {
+        let mut form = FormState::new()
+            .with_field(FormField::new(
+                TextPrompt::from("Language"),
+                TextState::new().with_completer(|value: &str, _position: usize| {
+                    ["rust", "ruby"]
+                        .iter()
+                        .filter(|language| language.starts_with(value))
+                        .map(ToString::to_string)
+                        .collect()
+                }),
+            ))
+            .with_field(FormField::new(TextPrompt::from("Name"), TextState::new()));
+        type_str(&mut form, "rus");
+        form.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(form.focused(), Some(0));
+        assert_eq!(form.fields()[0].state().value(), "rust");
+        form.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(form.focused(), Some(1));
+        // a field without a completer leaves Tab to the form
+        form.handle_key_event(KeyCode::BackTab.into());
+        assert_eq!(form.focused(), Some(1));
+    }
+
+    #[test]
+    fn completion_focuses_next_pending_field() {
+        let mut form = form();
+        form.focus(1);
+        form.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(form.focused(), Some(2));
+        form.handle_key_event(KeyCode::Tab.into());
+        // the completed field is skipped
+        assert_eq!(form.focused(), Some(0));
+    }
+
+    #[test]
+    fn done_when_no_field_is_pending() {
+        let mut form = form();
+        type_str(&mut form, "Ada");
+        form.handle_key_event(KeyCode::Enter.into());
+        form.handle_key_event(KeyCode::Tab.into());
+        type_str(&mut form, "ada@example.com");
+        form.handle_key_event(KeyCode::Enter.into());
+        // the optional field is still pending
+        assert_eq!(form.status(), Status::Pending);
+        assert_eq!(form.focused(), Some(1));
+        type_str(&mut form, "ada");
+        form.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(form.status(), Status::Done);
+        assert_eq!(form.focused(), None);
+    }
+
+    #[test]
+    fn optional_field_after_required_fields() {
+        let mut form = FormState::new()
+            .with_field(FormField::new(TextPrompt::from("Name"), TextState::new()))
+            .with_field(
+                FormField::new(TextPrompt::from("Nickname"), TextState::new()).with_required(false),
+            );
+        type_str(&mut form, "Ada");
+        form.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(form.status(), Status::Pending);
+        assert_eq!(form.focused(), Some(1));
+        type_str(&mut form, "ada");
+        form.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(form.status(), Status::Done);
+        assert_eq!(form.fields()[1].state().value(), "ada");
+    }
+
+    #[test]
+    fn only_optional_fields() {
+        let mut form = FormState::new()
+            .with_field(
+                FormField::new(TextPrompt::from("Nickname"), TextState::new()).with_required(false),
+            )
+            .with_field(
+                FormField::new(TextPrompt::from("Website"), TextState::new()).with_required(false),
+            );
+        type_str(&mut form, "ada");
+        assert_eq!(form.status(), Status::Pending);
+        assert_eq!(form.fields()[0].state().value(), "ada");
+        form.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(form.status(), Status::Pending);
+        form.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert_eq!(form.status(), Status::Done);
+        assert!(!form.fields()[1].is_finished());
+    }
+
+    #[test]
+    fn submit_requires_required_fields() {
+        let mut form = form();
+        form.focus(1);
+        form.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert_eq!(form.status(), Status::Pending);
+        assert_eq!(form.focused(), Some(0));
+        type_str(&mut form, "Ada");
+        form.handle_key_event(KeyCode::Enter.into());
+        form.handle_key_event(KeyCode::Tab.into());
+        type_str(&mut form, "ada@example.com");
+        form.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(form.status(), Status::Pending);
+        form.submit();
+        assert_eq!(form.status(), Status::Done);
+        assert_eq!(form.focused(), None);
+        assert!(!form.fields()[1].is_finished());
+    }
+
+    #[test]
+    fn abort() {
+        let mut form = form();
+        form.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(form.status(), Status::Aborted);
+        assert_eq!(form.focused(), None);
+    }
+
+    #[test]
+    fn height() {
+        let mut field = FormField::new(DatePrompt::from("Date"), DateState::new()).with_height(9);
+        assert_eq!(field.height(), 9);
+        field.state_mut().handle_key_event(KeyCode::Enter.into());
+        assert_eq!(field.height(), 1);
+    }
+}
@@ -0,0 +1,173 @@
+use itertools::Itertools;
+
+/// A candidate that matches a fuzzy pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FuzzyMatch {
+    /// The index of the candidate in the list of candidates.
+    pub index: usize,
+    /// The score of the match. Higher scores are better matches.
+    pub score: i64,
+    /// The character positions in the candidate that matched the pattern.
+    pub positions: Vec<usize>,
+}
+
+/// Matches the pattern against the candidates, returning the matches from best to worst.
+///
+/// A candidate matches if it contains all characters of the pattern in order, ignoring case.
+/// Consecutive characters and characters at the start of words score higher, and gaps between
+/// matched characters score lower. Matches with the same score keep the order of the candidates.
+/// An empty pattern matches every candidate.
+pub fn fuzzy_filter<'a>(
+    pattern: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<FuzzyMatch> {
+    candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| {
+            fuzzy_match(pattern, candidate).map(|(score, positions)| FuzzyMatch {
+                index,
+                score,
+                positions,
+            })
+        })
+        .sorted_by_key(|m| -m.score)
+        .collect()
+}
+
+/// Matches the pattern against a candidate, returning the score and matched character positions.
+///
+/// See [`fuzzy_filter`] for how matches are scored.
+#[must_use]
+pub fn fuzzy_match(pattern: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    let pattern = pattern.chars().collect_vec();
+    let candidate = candidate.chars().collect_vec();
+    if pattern.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    // best[i][j] is the best score matching pattern[..=i] with pattern[i] at candidate[j], along
+    // with the position of pattern[i - 1] that it was reached from.
+    let mut best: Vec<Vec<Option<(i64, usize)>>> = vec![vec![None; candidate.len()]; pattern.len()];
+    for (i, &p) in pattern.iter().enumerate() {
+        for (j, &c) in candidate.iter().enumerate() {
+            if !eq_ignore_case(c, p) {
+                continue;
+            }
+            let score = char_score(&candidate, j);
+            best[i][j] = if i == 0 {
+                Some((score - to_i64(j).min(5), j))
+            } else {
+                let previous = &best[i - 1];
+                (0..j)
+                    .filter_map(|k| previous[k].map(|(s, _)| (s + gap_score(k, j) + score, k)))
+                    .fold(None, |best, candidate| match best {
+                        Some((score, _)) if score >= candidate.0 => best,
+                        _ => Some(candidate),
+                    })
+            };
+        }
+    }
+
+    let last = pattern.len() - 1;
+    let (mut j, (score, _)) = best[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, cell)| cell.map(|cell| (j, cell)))
+        .fold(
+            None,
+            |best: Option<(usize, (i64, usize))>, candidate| match best {
+                Some((_, (score, _))) if score >= candidate.1 .0 => best,
+                _ => Some(candidate),
+            },
+        )?;
+    let mut positions = vec![0; pattern.len()];
+    for i in (0..=last).rev() {
+        positions[i] = j;
+        j = best[i][j].map_or(j, |(_, previous)| previous);
+    }
+    Some((score, positions))
+}
+
+/// The score of matching a character at the given position.
+fn char_score(candidate: &[char], position: usize) -> i64 {
+    if is_word_start(candidate, position) {
+        4
+    } else {
+        1
+    }
+}
+
+/// The score of matching consecutive pattern characters at the given positions.
+fn gap_score(previous: usize, position: usize) -> i64 {
+    match position - previous - 1 {
+        0 => 5,
+        gap => -to_i64(gap).min(5),
+    }
+}
+
+fn is_word_start(candidate: &[char], position: usize) -> bool {
+    let Some(previous) = position.checked_sub(1).map(|i| candidate[i]) else {
+        return true;
+    };
+    let current = candidate[position];
+    !previous.is_alphanumeric() || (previous.is_lowercase() && current.is_uppercase())
+}
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn to_i64(value: usize) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(fuzzy_match("", "anything"), Some((0, vec![])));
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(fuzzy_match("xyz", "anything"), None);
+        assert_eq!(fuzzy_match("ba", "ab"), None);
+    }
+
+    #[test]
+    fn positions() {
+        let (_, positions) = fuzzy_match("tp", "text_prompt").unwrap();
+        assert_eq!(positions, [0, 5]);
+    }
+
+    #[test]
+    fn ignores_case() {
+        let (_, positions) = fuzzy_match("TP", "tui_prompts").unwrap();
+        assert_eq!(positions, [0, 4]);
+    }
+
+    #[test]
+    fn prefers_word_starts() {
+        // the `p` of `prompt` is preferred over the `p` in `example`
+        let (_, positions) = fuzzy_match("ep", "example_prompt").unwrap();
+        assert_eq!(positions, [0, 8]);
+    }
+
+    #[test]
+    fn filter_orders_by_score() {
+        let candidates = ["tui-prompts", "prompt", "ratatui", "pr"];
+        let matches = fuzzy_filter("pr", candidates);
+        let order = matches.iter().map(|m| candidates[m.index]).collect_vec();
+        assert_eq!(order, ["prompt", "pr", "tui-prompts"]);
+    }
+
+    #[test]
+    fn filter_empty_pattern_keeps_order() {
+        let candidates = ["b", "a", "c"];
+        let matches = fuzzy_filter("", candidates);
+        assert_eq!(matches.iter().map(|m| m.index).collect_vec(), [0, 1, 2]);
+    }
+}
@@ -0,0 +1,518 @@
+use std::io::{self, Stderr};
+
+use ratatui::{
+    backend::{ClearType, WindowSize},
+    buffer::Cell,
+    crossterm::{
+        event::{self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyEvent},
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode},
+    },
+    prelude::*,
+    TerminalOptions, Viewport,
+};
+
+use zeroize::Zeroize;
+
+use crate::{is_interactive, prelude::*, Answers, NonInteractive, State};
+
+/// Runs prompts in an inline viewport below the cursor, in the normal screen rather than the
+/// alternate screen.
+///
+/// The viewport is sized to the lines the prompt renders, growing and shrinking as it changes,
+/// e.g. as the dropdown of an [`AutocompletePrompt`] is filtered. Once a prompt is finished, its
+/// collapsed line is printed into the scrollback above the viewport and the viewport is cleared,
+/// so that the terminal history reads like a transcript of the answers.
+///
+/// ```no_run
+/// use tui_prompts::prelude::*;
+///
+/// let mut runner = InlineRunner::stderr()?;
+/// let name = runner.run(TextPrompt::from("Name"), TextState::new())?;
+/// let email = runner.run(TextPrompt::from("Email"), TextState::new())?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct InlineRunner<B: Backend> {
+    backend: B,
+    max_height: Option<u16>,
+    raw_mode: bool,
+}
+
+impl InlineRunner<CrosstermBackend<Stderr>> {
+    /// Creates a runner that renders to stderr, with raw mode and bracketed paste enabled until it
+    /// is dropped.
+    ///
+    /// Raw mode is not enabled if stdin is not a terminal, see [`is_interactive`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if raw mode cannot be enabled.
+    pub fn stderr() -> io::Result<Self> {
+        let raw_mode = is_interactive();
+        if raw_mode {
+            enable_raw_mode()?;
+            if let Err(err) = execute!(io::stderr(), EnableBracketedPaste) {
+                let _ = disable_raw_mode();
+                return Err(err);
+            }
+        }
+        Ok(Self {
+            backend: CrosstermBackend::new(io::stderr()),
+            max_height: None,
+            raw_mode,
+        })
+    }
+}
+
+impl<B: Backend> InlineRunner<B> {
+    /// Creates a runner that renders to the backend, which is expected to be in raw mode.
+    #[must_use]
+    pub const fn new(backend: B) -> Self {
+        Self {
+            backend,
+            max_height: None,
+            raw_mode: false,
+        }
+    }
+
+    /// Sets the maximum number of lines of the viewport, which defaults to the height of the
+    /// terminal. Lines of the prompt beyond it are cut off.
+    #[must_use]
+    pub const fn with_max_height(mut self, max_height: u16) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    #[must_use]
+    pub const fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Runs the prompt until it is finished, reading key events from the terminal.
+    ///
+    /// If stdin is not a terminal, the prompt is answered from the next line of stdin instead, see
+    /// [`NonInteractive`].
+    ///
+    /// Returns the state once the prompt is done, or `None` if it is aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing to the terminal or reading an event fails, or if the line read
+    /// from stdin does not answer the prompt.
+    pub fn run<P, S>(&mut self, prompt: P, state: S) -> io::Result<Option<S>>
+    where
+        P: Prompt<State = S> + Clone,
+        S: State,
+    {
+        if !is_interactive() {
+            return NonInteractive::stdio().run(prompt, state);
+        }
+        self.run_with(prompt, state, event::read)
+    }
+
+    /// Asks the questions of the questionnaire until it is finished, rendering the answered
+    /// questions as summary lines above the current question.
+    ///
+    /// If stdin is not a terminal, the questions are answered from lines of stdin instead, see
+    /// [`NonInteractive::run_questionnaire`].
+    ///
+    /// Returns the answers once the questionnaire is done, or `None` if a question is aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing to the terminal or reading an event fails, or if the lines read
+    /// from stdin do not answer the questions.
+    pub fn run_questionnaire(
+        &mut self,
+        state: QuestionnaireState<'_>,
+    ) -> io::Result<Option<Answers>> {
+        if !is_interactive() {
+            return NonInteractive::stdio().run_questionnaire(state);
+        }
+        let state = self.run_with(Questionnaire::new(), state, event::read)?;
+        Ok(state.and_then(QuestionnaireState::into_answers))
+    }
+
+    /// Runs the prompt until it is finished, handling the events read by `read_event`.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn run_with<P, S>(
+        &mut self,
+        prompt: P,
+        mut state: S,
+        mut read_event: impl FnMut() -> io::Result<Event>,
+    ) -> io::Result<Option<S>>
+    where
+        P: Prompt<State = S> + Clone,
+        S: RunnerState,
+    {
+        state.focus();
+        let (width, max_height) = self.bounds()?;
+        let mut height = required_height(&prompt, &mut state, width, max_height);
+        let mut terminal = self.terminal(height)?;
+        while !state.status().is_finished() {
+            terminal.draw(|frame| prompt.clone().draw(frame, frame.size(), &mut state))?;
+            handle_event(&mut state, read_event()?);
+            let next_height = required_height(&prompt, &mut state, width, max_height);
+            if next_height != height {
+                // the viewport of a terminal cannot be resized, so the viewport is cleared and a
+                // new one is created from its top line
+                terminal.clear()?;
+                drop(terminal);
+                height = next_height;
+                terminal = self.terminal(height)?;
+            }
+        }
+        finish(&mut terminal, prompt, &mut state, width, max_height)?;
+        Ok(state.status().is_done().then_some(state))
+    }
+
+    /// The width of the viewport and the maximum number of lines of the viewport.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn bounds(&self) -> io::Result<(u16, u16)> {
+        let size = self.backend.size()?;
+        let max_height = self.max_height.unwrap_or(size.height).clamp(1, size.height);
+        Ok((size.width, max_height))
+    }
+
+    /// Creates a terminal with an inline viewport of the height below the cursor.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn terminal(&mut self, height: u16) -> io::Result<Terminal<BackendRef<'_, B>>> {
+        let options = TerminalOptions {
+            viewport: Viewport::Inline(height),
+        };
+        Terminal::with_options(BackendRef(&mut self.backend), options)
+    }
+}
+
+/// The state of what a runner runs: the state of a prompt, or a [`QuestionnaireState`], which asks
+/// the prompts of its questions one after another.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) trait RunnerState {
+    fn status(&self) -> Status;
+    fn focus(&mut self);
+    fn blur(&mut self);
+    /// The position of the cursor, if a prompt is focused.
+    fn focused_cursor(&self) -> Option<(u16, u16)>;
+    fn handle_key_event(&mut self, key_event: KeyEvent);
+    fn handle_paste(&mut self, text: &str);
+}
+
+impl<S: State + ?Sized> RunnerState for S {
+    fn status(&self) -> Status {
+        State::status(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self);
+    }
+
+    fn blur(&mut self) {
+        State::blur(self);
+    }
+
+    fn focused_cursor(&self) -> Option<(u16, u16)> {
+        self.is_focused().then(|| self.cursor())
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        State::handle_key_event(self, key_event);
+    }
+
+    fn handle_paste(&mut self, text: &str) {
+        State::handle_paste(self, text);
+    }
+}
+
+/// The questionnaire focuses its questions as they are asked and blurs them once they are answered.
+impl RunnerState for QuestionnaireState<'_> {
+    fn status(&self) -> Status {
+        QuestionnaireState::status(self)
+    }
+
+    fn focus(&mut self) {}
+
+    fn blur(&mut self) {}
+
+    fn focused_cursor(&self) -> Option<(u16, u16)> {
+        self.current_question()
+            .and_then(Question::state)
+            .and_then(RunnerState::focused_cursor)
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        QuestionnaireState::handle_key_event(self, key_event);
+    }
+
+    fn handle_paste(&mut self, text: &str) {
+        QuestionnaireState::handle_paste(self, text);
+    }
+}
+
+/// Routes key events and pasted text to the state. Pasted text is zeroized once it is handled, as
+/// it may be a password.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn handle_event<S: RunnerState + ?Sized>(state: &mut S, event: Event) {
+    match event {
+        Event::Key(key_event) => state.handle_key_event(key_event),
+        Event::Paste(mut text) => {
+            state.handle_paste(&text);
+            text.zeroize();
+        }
+        _ => {}
+    }
+}
+
+/// Prints the finished prompt into the scrollback above the viewport and clears the viewport.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn finish<B, P, S>(
+    terminal: &mut Terminal<B>,
+    prompt: P,
+    state: &mut S,
+    width: u16,
+    max_height: u16,
+) -> io::Result<()>
+where
+    B: Backend,
+    P: Prompt<State = S> + Clone,
+    S: RunnerState,
+{
+    state.blur();
+    let height = required_height(&prompt, state, width, max_height);
+    terminal.insert_before(height, |buf| prompt.render(buf.area, buf, state))?;
+    terminal.show_cursor()
+}
+
+impl<B: Backend> Drop for InlineRunner<B> {
+    fn drop(&mut self) {
+        if self.raw_mode {
+            // errors cannot be returned from drop, and the terminal is unusable either way
+            let _ = execute!(io::stderr(), DisableBracketedPaste);
+            let _ = disable_raw_mode();
+        }
+    }
+}
+
+/// The number of lines the prompt renders, up to the last line that is not blank or has the
+/// cursor.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn required_height<P, S>(prompt: &P, state: &mut S, width: u16, max_height: u16) -> u16
+where
+    P: Prompt<State = S> + Clone,
+    S: RunnerState,
+{
+    let area = Rect::new(0, 0, width, max_height);
+    let mut buffer = Buffer::empty(area);
+    prompt.clone().render(area, &mut buffer, state);
+    let blank = Cell::default();
+    let last_line = (0..area.height)
+        .rev()
+        .find(|&y| (0..area.width).any(|x| *buffer.get(x, y) != blank))
+        .unwrap_or(0);
+    let cursor_line = state.focused_cursor().map_or(0, |(_, y)| y);
+    (last_line.max(cursor_line) + 1).min(max_height)
+}
+
+/// A backend that borrows another backend, so that a new [`Terminal`] can be created over the same
+/// backend whenever the viewport is resized.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct BackendRef<'b, B>(&'b mut B);
+
+impl<B: Backend> Backend for BackendRef<'_, B> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        self.0.draw(content)
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        self.0.append_lines(n)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.0.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.0.show_cursor()
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        self.0.get_cursor()
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.0.set_cursor(x, y)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.0.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.0.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        self.0.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.0.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        backend::TestBackend,
+        crossterm::event::{KeyCode, KeyEvent},
+    };
+
+    use super::*;
+
+    fn events(keys: &[KeyCode]) -> impl FnMut() -> io::Result<Event> + '_ {
+        let mut keys = keys.iter();
+        move || {
+            let key = keys.next().expect("the prompt should be finished");
+            Ok(Event::Key(KeyEvent::from(*key)))
+        }
+    }
+
+    fn runner() -> InlineRunner<TestBackend> {
+        InlineRunner::new(TestBackend::new(20, 6))
+    }
+
+    #[test]
+    fn required_height_of_prompts() {
+        let prompt = AutocompletePrompt::from("Language");
+        let mut state = AutocompleteState::new(["rust", "ruby", "python"]);
+        assert_eq!(required_height(&prompt, &mut state, 20, 10), 4);
+        assert_eq!(required_height(&prompt, &mut state, 20, 2), 2);
+        let prompt = TextPrompt::from("Name");
+        assert_eq!(required_height(&prompt, &mut TextState::new(), 20, 10), 1);
+    }
+
+    #[test]
+    fn finished_prompts_are_left_in_scrollback() {
+        let mut runner = runner();
+        let name = runner
+            .run_with(
+                TextPrompt::from("Name").with_theme(Theme::new().no_color()),
+                TextState::new(),
+                events(&[KeyCode::Char('A'), KeyCode::Enter]),
+            )
+            .unwrap();
+        assert_eq!(name.unwrap().value(), "A");
+        let language = runner
+            .run_with(
+                AutocompletePrompt::from("Language").with_theme(Theme::new().no_color()),
+                AutocompleteState::new(["rust", "ruby", "python"]),
+                events(&[KeyCode::Char('p'), KeyCode::Enter]),
+            )
+            .unwrap();
+        assert_eq!(language.unwrap().value(), "python");
+        runner.backend().assert_buffer(&Buffer::with_lines([
+            "✔ Name › A          ",
+            "✔ Language › python ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+        ]));
+    }
+
+    #[test]
+    fn run_until_done() {
+        let mut runner = InlineRunner::new(TestBackend::new(20, 3));
+        let keys = [KeyCode::Char('A'), KeyCode::Char('d'), KeyCode::Enter];
+        let state = runner
+            .run_with(
+                TextPrompt::from("Name").with_theme(Theme::new().no_color()),
+                TextState::new(),
+                events(&keys),
+            )
+            .unwrap();
+        assert_eq!(state.unwrap().value(), "Ad");
+        runner.backend().assert_buffer(&Buffer::with_lines([
+            "✔ Name › Ad         ",
+            "                    ",
+            "                    ",
+        ]));
+    }
+
+    #[test]
+    fn run_with_paste() {
+        let mut events = [
+            Event::Paste("Ada\n".to_string()),
+            Event::Key(KeyEvent::from(KeyCode::Enter)),
+        ]
+        .into_iter();
+        let state = runner()
+            .run_with(TextPrompt::from("Name"), TextState::new(), || {
+                Ok(events.next().expect("the prompt should be finished"))
+            })
+            .unwrap();
+        assert_eq!(state.unwrap().value(), "Ada");
+    }
+
+    #[test]
+    fn run_questionnaire() {
+        let state = QuestionnaireState::new()
+            .with_question(Question::new(
+                "name",
+                TextPrompt::from("Name").with_theme(Theme::new().no_color()),
+                TextState::new(),
+            ))
+            .with_question(Question::new(
+                "continue",
+                ConfirmPrompt::from("Continue?").with_theme(Theme::new().no_color()),
+                ConfirmState::new(),
+            ));
+        let keys = [KeyCode::Char('A'), KeyCode::Enter, KeyCode::Char('y')];
+        let mut runner = runner();
+        let state = runner
+            .run_with(Questionnaire::new(), state, events(&keys))
+            .unwrap();
+        let answers = state.and_then(QuestionnaireState::into_answers).unwrap();
+        assert_eq!(answers.get("name").and_then(Answer::as_text), Some("A"));
+        assert_eq!(
+            answers.get("continue").and_then(Answer::as_bool),
+            Some(true)
+        );
+        runner.backend().assert_buffer(&Buffer::with_lines([
+            "✔ Name › A          ",
+            "✔ Continue? › yes   ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+        ]));
+    }
+
+    #[test]
+    fn run_until_aborted() {
+        let mut runner = runner();
+        let state = runner
+            .run_with(
+                ConfirmPrompt::from("Continue?").with_theme(Theme::new().no_color()),
+                ConfirmState::new(),
+                events(&[KeyCode::Char('x'), KeyCode::Esc]),
+            )
+            .unwrap();
+        assert!(state.is_none());
+        runner.backend().assert_buffer(&Buffer::with_lines([
+            "✘ Continue? ›       ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+        ]));
+    }
+}
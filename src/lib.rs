@@ -1,23 +1,141 @@
 #![warn(clippy::pedantic, clippy::nursery, clippy::cargo, unused)]
+#![allow(clippy::multiple_crate_versions)]
 
+mod answer;
+mod ask;
+#[cfg(feature = "async")]
+mod async_runner;
+mod autocomplete_prompt;
+mod autocomplete_state;
+mod completion;
+mod confirm_prompt;
+mod confirm_state;
+mod fuzzy;
+mod inline_runner;
+mod mask;
+mod non_interactive;
 mod prompt;
+mod prompt_form;
+mod secret;
+mod segment;
 mod status;
+#[cfg(test)]
+mod test_utils;
+mod theme;
 
+mod date_prompt;
+mod date_state;
+mod duration_prompt;
+mod duration_state;
+mod file_picker_prompt;
+mod file_picker_state;
+mod form;
+mod form_state;
+mod password_confirm_prompt;
+mod password_confirm_state;
+mod password_strength;
+mod path_prompt;
+mod path_state;
+mod prefill;
+mod questionnaire;
+mod questionnaire_state;
 mod text_prompt;
 mod text_state;
+mod time_prompt;
+mod time_state;
 
+pub use answer::*;
+pub use ask::*;
+#[cfg(feature = "async")]
+pub use async_runner::*;
+pub use autocomplete_prompt::*;
+pub use autocomplete_state::*;
+pub use completion::*;
+pub use confirm_prompt::*;
+pub use confirm_state::*;
+pub use fuzzy::*;
+pub use mask::*;
+pub use non_interactive::*;
 pub use prompt::*;
+pub use prompt_form::*;
+pub use secret::*;
 pub use status::*;
+pub use theme::*;
 
+pub use date_prompt::*;
+pub use date_state::*;
+pub use duration_prompt::*;
+pub use duration_state::*;
+pub use file_picker_prompt::*;
+pub use file_picker_state::*;
+pub use form::*;
+pub use form_state::*;
+pub use inline_runner::*;
+pub use password_confirm_prompt::*;
+pub use password_confirm_state::*;
+pub use password_strength::*;
+pub use path_prompt::*;
+pub use path_state::*;
+pub use prefill::*;
+pub use questionnaire::*;
+pub use questionnaire_state::*;
 pub use text_prompt::*;
 pub use text_state::*;
+pub use time_prompt::*;
+pub use time_state::*;
+#[cfg(feature = "derive")]
+pub use tui_prompts_derive::Prompt;
 
 pub mod prelude {
+    pub use crate::Answer;
+    pub use crate::Answers;
+    pub use crate::Ask;
+    pub use crate::AskOptions;
+    pub use crate::AskText;
+    #[cfg(feature = "async")]
+    pub use crate::AsyncRunner;
+    pub use crate::AutocompletePrompt;
+    pub use crate::AutocompleteState;
+    pub use crate::Choice;
+    pub use crate::Completer;
+    pub use crate::ConfirmPrompt;
+    pub use crate::ConfirmState;
+    pub use crate::DatePrompt;
+    pub use crate::DateState;
+    pub use crate::DurationPrompt;
+    pub use crate::DurationState;
+    pub use crate::FilePickerPrompt;
+    pub use crate::FilePickerState;
     pub use crate::FocusState;
+    pub use crate::Form;
+    pub use crate::FormField;
+    pub use crate::FormState;
+    pub use crate::FromAnswer;
+    pub use crate::InlineRunner;
+    pub use crate::Mask;
+    pub use crate::MaskedValue;
+    pub use crate::NonInteractive;
+    pub use crate::PasswordConfirmPrompt;
+    pub use crate::PasswordConfirmState;
+    pub use crate::PasswordStrength;
+    pub use crate::PathExistence;
+    pub use crate::PathKind;
+    pub use crate::PathPrompt;
+    pub use crate::PathState;
+    pub use crate::Prefill;
     pub use crate::Prompt;
+    pub use crate::PromptForm;
+    pub use crate::Question;
+    pub use crate::Questionnaire;
+    pub use crate::QuestionnaireState;
     pub use crate::State;
     pub use crate::Status;
+    pub use crate::TextCase;
     pub use crate::TextPrompt;
     pub use crate::TextRenderStyle;
     pub use crate::TextState;
+    pub use crate::Theme;
+    pub use crate::TimePrompt;
+    pub use crate::TimeState;
+    pub use crate::ToAnswer;
 }
@@ -0,0 +1,255 @@
+/// A pattern that constrains the input of a [`TextState`] to fixed slots, e.g. `(###) ###-####`
+/// for a phone number.
+///
+/// Each character of the pattern is either a slot for one typed character or a literal that is
+/// rendered automatically:
+/// - `#`: a digit
+/// - `@`: a letter
+/// - `*`: a letter or digit
+/// - `\`: escapes the next character, e.g. `\#` for a literal `#`
+/// - anything else: a literal
+///
+/// [`TextState`]: crate::TextState
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mask {
+    items: Vec<MaskItem>,
+}
+
+/// A character of a [`Mask`] pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaskItem {
+    /// A slot for a digit.
+    Digit,
+    /// A slot for a letter.
+    Letter,
+    /// A slot for a letter or digit.
+    Alphanumeric,
+    /// A literal character, rendered automatically.
+    Literal(char),
+}
+
+impl MaskItem {
+    /// Whether the item is a slot that accepts the character.
+    #[must_use]
+    pub fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+
+    /// Whether the item is a slot for a typed character.
+    #[must_use]
+    pub const fn is_slot(self) -> bool {
+        !matches!(self, Self::Literal(_))
+    }
+}
+
+impl Mask {
+    /// Creates a mask from a pattern like `(###) ###-####`.
+    #[must_use]
+    pub fn new(pattern: &str) -> Self {
+        let mut items = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            items.push(match c {
+                '#' => MaskItem::Digit,
+                '@' => MaskItem::Letter,
+                '*' => MaskItem::Alphanumeric,
+                '\\' => MaskItem::Literal(chars.next().unwrap_or('\\')),
+                c => MaskItem::Literal(c),
+            });
+        }
+        Self { items }
+    }
+
+    /// The characters of the pattern.
+    #[must_use]
+    pub fn items(&self) -> &[MaskItem] {
+        &self.items
+    }
+
+    /// The number of slots, which is the maximum length of the raw value.
+    #[must_use]
+    pub fn slots(&self) -> usize {
+        self.items.iter().filter(|item| item.is_slot()).count()
+    }
+
+    /// Whether the raw value fills the slots it covers, e.g. no letter in a digit slot.
+    #[must_use]
+    pub fn fits(&self, raw: &str) -> bool {
+        let mut slots = self.items.iter().filter(|item| item.is_slot());
+        raw.chars()
+            .all(|c| slots.next().is_some_and(|item| item.accepts(c)))
+    }
+
+    /// Whether the raw value fills all slots.
+    #[must_use]
+    pub fn is_complete(&self, raw: &str) -> bool {
+        self.fits(raw) && raw.chars().count() == self.slots()
+    }
+
+    /// Extracts the raw value from raw or formatted text.
+    ///
+    /// Literals of the pattern are skipped, and characters that do not fit the next slot are
+    /// dropped, so that e.g. `555-123` pasted into `(###) ###-####` becomes `555123`.
+    #[must_use]
+    pub fn raw(&self, text: &str) -> String {
+        let mut raw = String::new();
+        let mut items = self.items.iter().peekable();
+        for c in text.chars() {
+            // skip the literals before the next slot, consuming the character if it is one of them
+            let mut literal = false;
+            while let Some(MaskItem::Literal(l)) = items.peek() {
+                items.next();
+                if *l == c {
+                    literal = true;
+                    break;
+                }
+            }
+            if literal {
+                continue;
+            }
+            match items.peek() {
+                Some(item) if item.accepts(c) => {
+                    raw.push(c);
+                    items.next();
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+        raw
+    }
+
+    /// Formats the raw value, rendering the literals between the filled slots, e.g. `(555) 12`.
+    ///
+    /// Literals after the last slot are only rendered once all slots are filled.
+    #[must_use]
+    pub fn format(&self, raw: &str) -> String {
+        let len = raw.chars().count();
+        let complete = len == self.slots();
+        let mut raw = raw.chars();
+        let mut filled = 0;
+        let mut text = String::new();
+        for item in &self.items {
+            match item {
+                MaskItem::Literal(l) if filled < len || complete => text.push(*l),
+                MaskItem::Literal(_) => break,
+                _ => {
+                    let Some(c) = raw.next() else {
+                        break;
+                    };
+                    text.push(c);
+                    filled += 1;
+                }
+            }
+        }
+        text
+    }
+
+    /// The pattern as rendered for an empty value, with `_` in place of each slot.
+    #[must_use]
+    pub fn placeholder(&self) -> String {
+        self.items
+            .iter()
+            .map(|item| match item {
+                MaskItem::Literal(l) => *l,
+                _ => '_',
+            })
+            .collect()
+    }
+
+    /// The column of the slot at the index in the rendered pattern, skipping over literals.
+    ///
+    /// An index past the last slot is the column after the pattern.
+    #[must_use]
+    pub fn column(&self, index: usize) -> usize {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.is_slot())
+            .nth(index)
+            .map_or(self.items.len(), |(column, _)| column)
+    }
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHONE: &str = "(###) ###-####";
+
+    #[test]
+    fn new() {
+        let mask = Mask::new(r"@\#*");
+        assert_eq!(
+            mask.items(),
+            [
+                MaskItem::Letter,
+                MaskItem::Literal('#'),
+                MaskItem::Alphanumeric
+            ]
+        );
+        assert_eq!(mask.slots(), 2);
+    }
+
+    #[test]
+    fn fits() {
+        let mask = Mask::new("@#");
+        assert!(mask.fits(""));
+        assert!(mask.fits("a1"));
+        assert!(!mask.fits("1"));
+        assert!(!mask.fits("a1b"));
+        assert!(mask.is_complete("a1"));
+        assert!(!mask.is_complete("a"));
+    }
+
+    #[rstest::rstest]
+    #[case::raw("5551234567", "5551234567")]
+    #[case::formatted("(555) 123-4567", "5551234567")]
+    #[case::partial("(555) 1", "5551")]
+    #[case::other_separators("555.123.4567", "5551234567")]
+    #[case::too_long("555123456789", "5551234567")]
+    fn raw(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(Mask::new(PHONE).raw(text), expected);
+    }
+
+    #[rstest::rstest]
+    #[case::empty("", "")]
+    #[case::first("5", "(5")]
+    #[case::literals_between("5551", "(555) 1")]
+    #[case::before_literal("555", "(555")]
+    #[case::complete("5551234567", "(555) 123-4567")]
+    fn format(#[case] raw: &str, #[case] expected: &str) {
+        assert_eq!(Mask::new(PHONE).format(raw), expected);
+    }
+
+    #[test]
+    fn trailing_literal() {
+        let mask = Mask::new("##%");
+        assert_eq!(mask.format("1"), "1");
+        assert_eq!(mask.format("12"), "12%");
+    }
+
+    #[test]
+    fn placeholder() {
+        assert_eq!(Mask::new(PHONE).placeholder(), "(___) ___-____");
+    }
+
+    #[test]
+    fn column() {
+        let mask = Mask::new(PHONE);
+        assert_eq!(mask.column(0), 1);
+        assert_eq!(mask.column(3), 6);
+        assert_eq!(mask.column(10), 14);
+    }
+}
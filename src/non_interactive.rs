@@ -0,0 +1,456 @@
+use std::io::{self, BufRead, IsTerminal, Stderr, StdinLock, Write};
+
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    prelude::*,
+    widgets::StatefulWidget,
+};
+use zeroize::Zeroize;
+
+use crate::{prelude::*, Answers, State};
+
+/// The width that prompts are rendered at in the transcript and in errors.
+const WIDTH: u16 = 80;
+
+/// The maximum number of lines that prompts are rendered at in the transcript and in errors.
+const MAX_HEIGHT: u16 = 100;
+
+/// Whether stdin is a terminal that prompts can read key events from.
+///
+/// When it is not, e.g. in a pipe or in CI, the runners fall back to [`NonInteractive`].
+#[must_use]
+pub fn is_interactive() -> bool {
+    io::stdin().is_terminal()
+}
+
+/// Answers prompts from lines of text rather than key events, for when stdin is not a terminal.
+///
+/// Each line is typed into the prompt and submitted with Enter, so the answer goes through the same
+/// validation and parsing as an interactive answer, and an empty line or the end of the input
+/// accepts the default of the prompt. A prompt that is answered in several steps reads a line for
+/// each step, e.g. a password and then its confirmation. An answer that the prompt rejects, or an
+/// empty line or the end of the input where the prompt has no default, is an error that shows the
+/// prompt and its validation error.
+///
+/// Finished prompts are written to the writer as plain text, as a transcript of the answers.
+///
+/// ```
+/// use tui_prompts::prelude::*;
+///
+/// let mut transcript = Vec::new();
+/// let mut answers = NonInteractive::new(&b"Ada\n"[..], &mut transcript);
+/// let name = answers.run(TextPrompt::from("Name"), TextState::new())?;
+/// assert_eq!(name.unwrap().value(), "Ada");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct NonInteractive<R: BufRead, W: Write> {
+    reader: R,
+    writer: W,
+}
+
+impl NonInteractive<StdinLock<'static>, Stderr> {
+    /// Reads answers from stdin and writes the transcript to stderr.
+    #[must_use]
+    pub fn stdio() -> Self {
+        Self::new(io::stdin().lock(), io::stderr())
+    }
+}
+
+impl<R: BufRead, W: Write> NonInteractive<R, W> {
+    #[must_use]
+    pub const fn new(reader: R, writer: W) -> Self {
+        Self { reader, writer }
+    }
+
+    /// Answers the prompt with the next line, or the next lines for a prompt that is answered in
+    /// several steps.
+    ///
+    /// Returns the state once the prompt is done, or `None` if it is aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidInput`] if the prompt rejects the line,
+    /// [`io::ErrorKind::UnexpectedEof`] if the input ends before the prompt is answered, or any
+    /// error of reading or writing.
+    // the prompt is taken by value like in the other runners
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn run<P, S>(&mut self, prompt: P, mut state: S) -> io::Result<Option<S>>
+    where
+        P: Prompt<State = S> + Clone,
+        S: State,
+    {
+        self.answer(&prompt, &mut state)?;
+        Ok(state.status().is_done().then_some(state))
+    }
+
+    /// Answers the prompt, see [`run`](Self::run).
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn answer<P, S>(&mut self, prompt: &P, state: &mut S) -> io::Result<()>
+    where
+        P: Prompt<State = S> + Clone,
+        S: State,
+    {
+        state.focus();
+        while state.status().is_pending() {
+            let mut line = self.read_line()?;
+            let eof = line.is_none();
+            let step = state.step();
+            let typed = type_line(state, line.as_deref());
+            line.zeroize();
+            if typed && state.status().is_pending() {
+                state.handle_key_event(KeyEvent::from(KeyCode::Enter));
+            }
+            if !typed || state.status().is_pending() && state.step() == step {
+                return Err(Self::rejected(prompt, state, eof));
+            }
+        }
+        state.blur();
+        self.write_transcript(prompt, state)
+    }
+
+    /// Answers the questions of the questionnaire with a line for each step, skipping the questions
+    /// whose condition is false.
+    ///
+    /// Returns the answers once the questionnaire is done, or `None` if it is aborted.
+    ///
+    /// # Errors
+    ///
+    /// See [`run`](Self::run).
+    pub fn run_questionnaire(
+        &mut self,
+        mut state: QuestionnaireState<'_>,
+    ) -> io::Result<Option<Answers>> {
+        let prompt = Questionnaire::new();
+        while let Some(current) = state.current() {
+            let mut line = self.read_line()?;
+            let eof = line.is_none();
+            let (step, typed) = state
+                .current_question_mut()
+                .and_then(Question::state_mut)
+                .map_or((0, true), |question| {
+                    (question.step(), type_line(question, line.as_deref()))
+                });
+            line.zeroize();
+            if typed && state.current() == Some(current) {
+                state.handle_key_event(KeyEvent::from(KeyCode::Enter));
+            }
+            let stuck = state.current() == Some(current)
+                && state
+                    .current_question()
+                    .and_then(Question::state)
+                    .map_or(0, State::step)
+                    == step;
+            if !typed || stuck {
+                return Err(Self::rejected(&prompt, &mut state, eof));
+            }
+        }
+        self.write_transcript(&prompt, &mut state)?;
+        Ok(state.into_answers())
+    }
+
+    /// The next line without its line ending, or `None` at the end of the input.
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let len = line.trim_end_matches(['\n', '\r']).len();
+        line.truncate(len);
+        Ok(Some(line))
+    }
+
+    fn rejected<P>(prompt: &P, state: &mut P::State, eof: bool) -> io::Error
+    where
+        P: StatefulWidget + Clone,
+    {
+        let text = plain_text(prompt, state);
+        if eof {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("the input ended without an answer to:\n{text}"),
+            )
+        } else {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid answer to:\n{text}"),
+            )
+        }
+    }
+
+    fn write_transcript<P>(&mut self, prompt: &P, state: &mut P::State) -> io::Result<()>
+    where
+        P: StatefulWidget + Clone,
+    {
+        writeln!(self.writer, "{}", plain_text(prompt, state))?;
+        self.writer.flush()
+    }
+}
+
+/// Types the line into the state, or returns false if the line is empty or missing and the prompt
+/// has neither a value nor a default that an empty answer accepts.
+fn type_line<S: State + ?Sized>(state: &mut S, line: Option<&str>) -> bool {
+    let line = line.unwrap_or_default();
+    if line.is_empty() && state.is_empty() && !state.has_default() {
+        return false;
+    }
+    type_value(state, line);
+    true
+}
+
+/// Types the value into the state, replacing its value unless the value is empty, e.g. the value
+/// of an autocomplete prompt that starts with a value.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn type_value<S: State + ?Sized>(state: &mut S, value: &str) {
+    if !value.is_empty() && !state.is_empty() {
+        state.handle_key_event(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    }
+    for c in value.chars() {
+        // e.g. a confirm prompt is answered by its first character
+        if !state.status().is_pending() {
+            break;
+        }
+        state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+    }
+}
+
+/// The symbols of the lines that the prompt renders, without trailing blank lines and spaces.
+fn plain_text<P>(prompt: &P, state: &mut P::State) -> String
+where
+    P: StatefulWidget + Clone,
+{
+    let area = Rect::new(0, 0, WIDTH, MAX_HEIGHT);
+    let mut buffer = Buffer::empty(area);
+    prompt.clone().render(area, &mut buffer, state);
+    let lines: Vec<String> = (0..area.height)
+        .map(|y| {
+            let line: String = (0..area.width).map(|x| buffer.get(x, y).symbol()).collect();
+            line.trim_end().to_string()
+        })
+        .collect();
+    let len = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+    lines[..len].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::temp_dir;
+
+    fn not_empty(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            Err("Required".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn short(value: &str) -> Result<(), String> {
+        if value.len() > 3 {
+            Err("Too long".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn prompt() -> TextPrompt<'static> {
+        TextPrompt::from("Name").with_theme(Theme::new().no_color())
+    }
+
+    #[test]
+    fn answers_and_transcript() {
+        let mut transcript = Vec::new();
+        let mut answers = NonInteractive::new(&b"Ada\ny\n"[..], &mut transcript);
+        let name = answers.run(prompt(), TextState::new()).unwrap();
+        assert_eq!(name.unwrap().value(), "Ada");
+        let confirm = answers
+            .run(
+                ConfirmPrompt::from("Continue?").with_theme(Theme::new().no_color()),
+                ConfirmState::new(),
+            )
+            .unwrap();
+        assert_eq!(confirm.unwrap().answer(), Some(true));
+        assert_eq!(
+            String::from_utf8(transcript).unwrap(),
+            "✔ Name › Ada\n✔ Continue? › yes\n"
+        );
+    }
+
+    #[test]
+    fn defaults() {
+        let mut answers = NonInteractive::new(&b"\n"[..], io::sink());
+        let state = TextState::new().with_default("Ada");
+        let name = answers.run(prompt(), state.clone()).unwrap();
+        assert_eq!(name.unwrap().value(), "Ada");
+        // at the end of the input
+        let name = answers.run(prompt(), state).unwrap();
+        assert_eq!(name.unwrap().value(), "Ada");
+    }
+
+    #[test]
+    fn invalid_answer() {
+        let mut answers = NonInteractive::new(&b"Ada\nGrace\n"[..], io::sink());
+        let state = TextState::new().with_validator(short);
+        answers.run(prompt(), state.clone()).unwrap();
+        let err = answers.run(prompt(), state).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "invalid answer to:\n? Name › Grace\nToo long"
+        );
+    }
+
+    #[test]
+    fn missing_answer() {
+        let mut answers = NonInteractive::new(&b""[..], io::sink());
+        let err = answers
+            .run(
+                ConfirmPrompt::from("Continue?").with_theme(Theme::new().no_color()),
+                ConfirmState::new(),
+            )
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            err.to_string(),
+            "the input ended without an answer to:\n? Continue? (y/n) ›"
+        );
+    }
+
+    #[test]
+    fn missing_text_answer() {
+        let mut answers = NonInteractive::new(&b""[..], io::sink());
+        let err = answers.run(prompt(), TextState::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            err.to_string(),
+            "the input ended without an answer to:\n? Name ›"
+        );
+        // an empty line is no answer either
+        let mut answers = NonInteractive::new(&b"\n"[..], io::sink());
+        let err = answers.run(prompt(), TextState::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "invalid answer to:\n? Name ›");
+    }
+
+    #[test]
+    fn password_confirm() {
+        let prompt = PasswordConfirmPrompt::from("Password").with_theme(Theme::new().no_color());
+        let mut answers = NonInteractive::new(&b"hunter2\nhunter2\n"[..], io::sink());
+        let state = answers
+            .run(prompt.clone(), PasswordConfirmState::new())
+            .unwrap();
+        assert_eq!(state.unwrap().password().value(), "hunter2");
+        let mut answers = NonInteractive::new(&b"hunter2\nhunter3\n"[..], io::sink());
+        let err = answers
+            .run(prompt.clone(), PasswordConfirmState::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let mut answers = NonInteractive::new(&b"hunter2\n"[..], io::sink());
+        let err = answers
+            .run(prompt, PasswordConfirmState::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn file_picker() {
+        let dir = temp_dir();
+        let mut answers = NonInteractive::new(&b"src\nlib\n"[..], io::sink());
+        let state = answers
+            .run(
+                FilePickerPrompt::from("File"),
+                FilePickerState::new(dir.path()),
+            )
+            .unwrap();
+        assert_eq!(
+            state.unwrap().selected(),
+            [dir.path().join("src").join("lib.rs")]
+        );
+    }
+
+    #[test]
+    fn questionnaire() {
+        let state = QuestionnaireState::new()
+            .with_question(Question::new("name", prompt(), TextState::new()))
+            .with_question(Question::new(
+                "language",
+                AutocompletePrompt::from("Language").with_theme(Theme::new().no_color()),
+                AutocompleteState::new(["rust", "python"]),
+            ));
+        let mut answers = NonInteractive::new(&b"Ada\npyth\n"[..], io::sink());
+        let answers = answers.run_questionnaire(state).unwrap().unwrap();
+        assert_eq!(answers.get("name").and_then(Answer::as_text), Some("Ada"));
+        assert_eq!(
+            answers.get("language").and_then(Answer::as_text),
+            Some("python")
+        );
+    }
+
+    #[test]
+    fn questionnaire_password_confirm() {
+        let state = QuestionnaireState::new()
+            .with_question(Question::new(
+                "password",
+                PasswordConfirmPrompt::from("Password").with_theme(Theme::new().no_color()),
+                PasswordConfirmState::new(),
+            ))
+            .with_question(Question::new("name", prompt(), TextState::new()));
+        let mut answers = NonInteractive::new(&b"hunter2\nhunter2\nAda\n"[..], io::sink());
+        let answers = answers.run_questionnaire(state).unwrap().unwrap();
+        assert_eq!(
+            answers
+                .get("password")
+                .and_then(Answer::as_secret)
+                .map(crate::Secret::expose_secret),
+            Some("hunter2")
+        );
+        assert_eq!(answers.get("name").and_then(Answer::as_text), Some("Ada"));
+    }
+
+    #[test]
+    fn empty_questionnaire() {
+        let mut answers = NonInteractive::new(&b""[..], io::sink());
+        let answers = answers
+            .run_questionnaire(QuestionnaireState::new())
+            .unwrap();
+        assert!(answers.unwrap().is_empty());
+    }
+
+    #[test]
+    fn questionnaire_invalid_answer() {
+        let state = QuestionnaireState::new()
+            .with_question(Question::new(
+                "name",
+                prompt(),
+                TextState::new().with_validator(not_empty),
+            ))
+            .with_question(Question::new("email", prompt(), TextState::new()));
+        let mut answers = NonInteractive::new(&b"\nada@example.com\n"[..], io::sink());
+        let err = answers.run_questionnaire(state).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn questionnaire_prefill_review() {
+        let state = QuestionnaireState::new()
+            .with_question(Question::new("name", prompt(), TextState::new()))
+            .with_question(Question::new("email", prompt(), TextState::new()))
+            .with_prefill(
+                Prefill::new()
+                    .with_value("name", "Ada")
+                    .with_value("email", "ada@example.com")
+                    .with_review(true),
+            );
+        // an empty line accepts the value, and a line replaces it
+        let mut answers = NonInteractive::new(&b"\ngrace@example.com\n"[..], io::sink());
+        let answers = answers.run_questionnaire(state).unwrap().unwrap();
+        assert_eq!(answers.get("name").and_then(Answer::as_text), Some("Ada"));
+        assert_eq!(
+            answers.get("email").and_then(Answer::as_text),
+            Some("grace@example.com")
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::borrow::Cow;
+
+use crate::prelude::*;
+
+use ratatui::{
+    prelude::*,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A prompt widget that asks for a password and then for a confirmation of it.
+///
+/// The password is rendered on the first line, the confirmation on the second line once the
+/// password is entered, and a mismatch error on the third line when the confirmation does not
+/// match. See [`PasswordConfirmState`] for the behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordConfirmPrompt<'a> {
+    /// The message to display before the password.
+    message: Cow<'a, str>,
+    /// The message to display before the confirmation.
+    confirm_message: Cow<'a, str>,
+    /// The error to display when the confirmation does not match the password.
+    mismatch_message: Cow<'a, str>,
+    /// The character displayed in place of each character of the password.
+    password_char: char,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
+}
+
+impl<'a> PasswordConfirmPrompt<'a> {
+    #[must_use]
+    pub const fn new(message: Cow<'a, str>) -> Self {
+        Self {
+            message,
+            confirm_message: Cow::Borrowed("Confirm password"),
+            mismatch_message: Cow::Borrowed("Passwords do not match"),
+            password_char: '*',
+            theme: Theme::new(),
+        }
+    }
+
+    /// Sets the message to display before the confirmation.
+    ///
+    /// Defaults to `Confirm password`.
+    #[must_use]
+    pub fn with_confirm_message(mut self, message: impl Into<Cow<'a, str>>) -> Self {
+        self.confirm_message = message.into();
+        self
+    }
+
+    /// Sets the error to display when the confirmation does not match the password.
+    ///
+    /// Defaults to `Passwords do not match`.
+    #[must_use]
+    pub fn with_mismatch_message(mut self, message: impl Into<Cow<'a, str>>) -> Self {
+        self.mismatch_message = message.into();
+        self
+    }
+
+    /// Sets the character displayed in place of each character of the password.
+    ///
+    /// Defaults to `*`.
+    #[must_use]
+    pub const fn with_password_char(mut self, password_char: char) -> Self {
+        self.password_char = password_char;
+        self
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn text_prompt(&self, message: Cow<'a, str>) -> TextPrompt<'a> {
+        TextPrompt::new(message)
+            .with_render_style(TextRenderStyle::Password)
+            .with_password_char(self.password_char)
+            .with_theme(self.theme.clone())
+    }
+}
+
+impl Prompt for PasswordConfirmPrompt<'_> {
+    /// Draws the prompt widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if state.is_focused() {
+            frame.set_cursor(state.cursor().0, state.cursor().1);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for PasswordConfirmPrompt<'a> {
+    type State = PasswordConfirmState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        // a finished question of a questionnaire is rendered on a single line, which is left to the
+        // password
+        let [password_area, confirm_area, error_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Max(1),
+            Constraint::Max(1),
+        ])
+        .areas(area);
+
+        let password_prompt = self.text_prompt(self.message.clone());
+        password_prompt.render(password_area, buf, state.password_mut());
+
+        if state.is_confirming() && !confirm_area.is_empty() {
+            let confirm_prompt = self.text_prompt(self.confirm_message.clone());
+            confirm_prompt.render(confirm_area, buf, state.confirmation_mut());
+        }
+
+        if state.is_mismatch() && !error_area.is_empty() {
+            Span::styled(self.mismatch_message, self.theme.error).render(error_area, buf);
+        }
+    }
+}
+
+impl<T> From<T> for PasswordConfirmPrompt<'static>
+where
+    T: Into<Cow<'static, str>>,
+{
+    fn from(message: T) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_macros::line;
+
+    use super::*;
+    use crate::test_utils::type_str;
+
+    #[test]
+    fn new() {
+        let prompt = PasswordConfirmPrompt::from("Password");
+        assert_eq!(prompt.message, "Password");
+        assert_eq!(prompt.confirm_message, "Confirm password");
+        assert_eq!(prompt.mismatch_message, "Passwords do not match");
+        assert_eq!(prompt.password_char, '*');
+        assert_eq!(prompt.theme, Theme::default());
+    }
+
+    #[test]
+    fn render_password() {
+        let prompt = PasswordConfirmPrompt::from("Password");
+        let mut state = PasswordConfirmState::new();
+        type_str(&mut state, "abc");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 3));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let expected = Buffer::with_lines([
+            line![
+                "?".cyan(),
+                " ",
+                "Password".bold(),
+                " › ".cyan().dim(),
+                "***",
+                "              "
+            ],
+            line![""],
+            line![""],
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.cursor(), (16, 0));
+    }
+
+    #[test]
+    fn render_confirmation() {
+        let prompt = PasswordConfirmPrompt::from("Password").with_confirm_message("Again");
+        let mut state = PasswordConfirmState::new();
+        type_str(&mut state, "abc");
+        state.complete();
+        type_str(&mut state, "a");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 3));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let expected = Buffer::with_lines([
+            line![
+                "✔".green(),
+                " ",
+                "Password".bold(),
+                " › ".cyan().dim(),
+                "***",
+                "              "
+            ],
+            line![
+                "?".cyan(),
+                " ",
+                "Again".bold(),
+                " › ".cyan().dim(),
+                "*",
+                "                   "
+            ],
+            line![""],
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.cursor(), (11, 1));
+    }
+
+    #[test]
+    fn render_mismatch() {
+        let prompt = PasswordConfirmPrompt::from("Password").with_mismatch_message("No match");
+        let mut state = PasswordConfirmState::new();
+        type_str(&mut state, "abc");
+        state.complete();
+        type_str(&mut state, "abd");
+        state.complete();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 3));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let expected = Buffer::with_lines([
+            line![
+                "✔".green(),
+                " ",
+                "Password".bold(),
+                " › ".cyan().dim(),
+                "***",
+                "              "
+            ],
+            line![
+                "?".cyan(),
+                " ",
+                "Confirm password".bold(),
+                " › ".cyan().dim(),
+                "         "
+            ],
+            line!["No match".red(), "                      "],
+        ]);
+        assert_eq!(buffer, expected);
+    }
+}
@@ -0,0 +1,310 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+use crate::{prelude::*, Secret, State};
+
+/// The state of a [`PasswordConfirmPrompt`].
+///
+/// The password is entered into a first field, then confirmed in a second field. If the
+/// confirmation does not match, the confirmation is cleared and a mismatch error is shown until the
+/// next key press. The prompt is only [`Status::Done`] once both fields match.
+///
+/// The password is only confirmed once its field is done, i.e. while the password is rejected by a
+/// validator or is too weak (see [`PasswordConfirmState::password_mut`]), Enter keeps the password
+/// field focused. An empty password is accepted unless such a validator rejects it.
+///
+/// Both fields are secret (see [`TextState::with_secret`]). Keybindings are those of
+/// [`TextState`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PasswordConfirmState<'a> {
+    status: Status,
+    focus: FocusState,
+    password: TextState<'a>,
+    confirmation: TextState<'a>,
+    confirming: bool,
+    mismatch: bool,
+}
+
+impl Default for PasswordConfirmState<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> PasswordConfirmState<'a> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            status: Status::Pending,
+            focus: FocusState::Unfocused,
+            password: TextState::new().with_secret(true),
+            confirmation: TextState::new().with_secret(true),
+            confirming: false,
+            mismatch: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_focus(mut self, focus: FocusState) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    /// The state of the password field.
+    #[must_use]
+    pub const fn password(&self) -> &TextState<'a> {
+        &self.password
+    }
+
+    /// A mutable reference to the state of the password field.
+    pub fn password_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.password
+    }
+
+    /// The state of the confirmation field.
+    #[must_use]
+    pub const fn confirmation(&self) -> &TextState<'a> {
+        &self.confirmation
+    }
+
+    /// A mutable reference to the state of the confirmation field.
+    pub fn confirmation_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.confirmation
+    }
+
+    /// Whether the password has been entered and is being confirmed.
+    #[must_use]
+    pub const fn is_confirming(&self) -> bool {
+        self.confirming
+    }
+
+    /// Whether the last confirmation did not match the password.
+    #[must_use]
+    pub const fn is_mismatch(&self) -> bool {
+        self.mismatch
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    /// Takes the confirmed password out of the state as a [`Secret`], clearing both fields.
+    pub fn take_secret(&mut self) -> Secret {
+        self.confirmation.truncate();
+        self.password.take_secret()
+    }
+
+    /// The field that is currently being edited.
+    const fn field(&self) -> &TextState<'a> {
+        if self.confirming {
+            &self.confirmation
+        } else {
+            &self.password
+        }
+    }
+
+    /// A mutable reference to the field that is currently being edited.
+    fn field_mut(&mut self) -> &mut TextState<'a> {
+        if self.confirming {
+            &mut self.confirmation
+        } else {
+            &mut self.password
+        }
+    }
+}
+
+impl State for PasswordConfirmState<'_> {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn status_mut(&mut self) -> &mut Status {
+        &mut self.status
+    }
+
+    fn focus_state_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    fn focus_state(&self) -> FocusState {
+        self.focus
+    }
+
+    fn position(&self) -> usize {
+        self.field().position()
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        self.field_mut().position_mut()
+    }
+
+    fn cursor(&self) -> (u16, u16) {
+        self.field().cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut (u16, u16) {
+        self.field_mut().cursor_mut()
+    }
+
+    /// The value of the field that is currently being edited.
+    fn value(&self) -> &str {
+        self.field().value()
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        self.field_mut().value_mut()
+    }
+
+    /// The password is the first step and its confirmation the second.
+    fn step(&self) -> usize {
+        usize::from(self.confirming)
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        if key_event.code == KeyCode::Enter {
+            self.complete();
+            return;
+        }
+        self.mismatch = false;
+        self.field_mut().handle_key_event(key_event);
+        if self.field().status().is_aborted() {
+            self.status = Status::Aborted;
+        }
+    }
+
+    fn complete(&mut self) {
+        if !self.confirming {
+            self.password.complete();
+            self.confirming = self.password.status().is_done();
+        } else if self.confirmation.value() == self.password.value() {
+            self.confirmation.complete();
+            self.status = Status::Done;
+        } else {
+            self.confirmation.truncate();
+            self.mismatch = true;
+        }
+    }
+
+    fn abort(&mut self) {
+        self.field_mut().abort();
+        self.status = Status::Aborted;
+    }
+
+    fn delete(&mut self) {
+        self.field_mut().delete();
+    }
+
+    fn backspace(&mut self) {
+        self.field_mut().backspace();
+    }
+
+    fn kill(&mut self) {
+        self.field_mut().kill();
+    }
+
+    fn truncate(&mut self) {
+        self.field_mut().truncate();
+    }
+
+    fn push(&mut self, c: char) {
+        self.field_mut().push(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::type_str;
+
+    #[test]
+    fn new() {
+        let state = PasswordConfirmState::new();
+        assert_eq!(state.status(), Status::Pending);
+        assert!(!state.is_confirming());
+        assert!(state.password().is_secret());
+        assert!(state.confirmation().is_secret());
+    }
+
+    #[test]
+    fn matching() {
+        let mut state = PasswordConfirmState::new();
+        type_str(&mut state, "hunter2");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert!(state.is_confirming());
+        assert_eq!(state.password().status(), Status::Done);
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.value(), "");
+
+        type_str(&mut state, "hunter2");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.take_secret().expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn mismatch() {
+        let mut state = PasswordConfirmState::new();
+        type_str(&mut state, "hunter2");
+        state.handle_key_event(KeyCode::Enter.into());
+        type_str(&mut state, "hunter3");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+        assert!(state.is_mismatch());
+        assert_eq!(state.confirmation().value(), "");
+        assert_eq!(state.position(), 0);
+
+        type_str(&mut state, "h");
+        assert!(!state.is_mismatch());
+        type_str(&mut state, "unter2");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+    }
+
+    #[test]
+    fn invalid_password() {
+        fn not_empty(value: &str) -> Result<(), String> {
+            if value.is_empty() {
+                Err("Required".into())
+            } else {
+                Ok(())
+            }
+        }
+        let mut state = PasswordConfirmState::new();
+        *state.password_mut() = TextState::new().with_secret(true).with_validator(not_empty);
+        state.handle_key_event(KeyCode::Enter.into());
+        assert!(!state.is_confirming());
+        assert_eq!(state.password().error(), Some("Required"));
+
+        type_str(&mut state, "hunter2");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert!(state.is_confirming());
+    }
+
+    #[test]
+    fn empty_password() {
+        let mut state = PasswordConfirmState::new();
+        state.handle_key_event(KeyCode::Enter.into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.take_secret().expose_secret(), "");
+    }
+
+    #[test]
+    fn abort() {
+        let mut state = PasswordConfirmState::new();
+        type_str(&mut state, "hunter2");
+        state.handle_key_event(KeyCode::Enter.into());
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+        assert_eq!(state.confirmation().status(), Status::Aborted);
+    }
+}
@@ -0,0 +1,206 @@
+use itertools::Itertools;
+use zeroize::Zeroizing;
+
+/// The estimated strength of a password.
+///
+/// Use [`TextState::with_password_strength`] to show a strength meter below a password input, and
+/// [`TextState::with_min_strength`] to require a minimum strength before the prompt completes.
+///
+/// [`TextState::with_password_strength`]: crate::TextState::with_password_strength
+/// [`TextState::with_min_strength`]: crate::TextState::with_min_strength
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PasswordStrength {
+    #[default]
+    VeryWeak,
+    Weak,
+    Fair,
+    Strong,
+    VeryStrong,
+}
+
+/// Passwords that are rejected regardless of their length or character classes.
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456",
+    "12345678",
+    "123456789",
+    "1234567890",
+    "111111",
+    "000000",
+    "abc123",
+    "admin",
+    "baseball",
+    "dragon",
+    "football",
+    "iloveyou",
+    "letmein",
+    "master",
+    "monkey",
+    "passw0rd",
+    "password",
+    "password1",
+    "password123",
+    "princess",
+    "qwerty",
+    "qwerty123",
+    "qwertyuiop",
+    "shadow",
+    "sunshine",
+    "superman",
+    "trustno1",
+    "welcome",
+];
+
+impl PasswordStrength {
+    /// All strengths, from weakest to strongest.
+    pub const ALL: [Self; 5] = [
+        Self::VeryWeak,
+        Self::Weak,
+        Self::Fair,
+        Self::Strong,
+        Self::VeryStrong,
+    ];
+
+    /// Estimates the strength of a password.
+    ///
+    /// This is a simple heuristic, not a guarantee:
+    /// - common passwords are always [`PasswordStrength::VeryWeak`]
+    /// - length of 8, 12 and 16 characters each add a point
+    /// - each character class (lowercase, uppercase, digits, other) beyond the first adds a point
+    /// - repeated characters or substrings (`aaa`, `abcabc`) and sequences (`abcd`, `4321`) each
+    ///   remove a point
+    /// - passwords shorter than 8 characters are at most [`PasswordStrength::Weak`], and shorter
+    ///   than 12 characters at most [`PasswordStrength::Strong`]
+    #[must_use]
+    pub fn estimate(password: &str) -> Self {
+        // the password is not copied, except into a buffer of its exact size that is zeroized
+        let mut chars = Zeroizing::new(Vec::with_capacity(password.chars().count()));
+        chars.extend(password.chars());
+        let common = COMMON_PASSWORDS.iter().any(|common| {
+            common
+                .chars()
+                .eq(password.chars().flat_map(char::to_lowercase))
+        });
+        if chars.is_empty() || common {
+            return Self::VeryWeak;
+        }
+
+        let length = [8, 12, 16]
+            .iter()
+            .filter(|&&min| chars.len() >= min)
+            .count();
+        let classes = [
+            chars.iter().any(char::is_ascii_lowercase),
+            chars.iter().any(char::is_ascii_uppercase),
+            chars.iter().any(char::is_ascii_digit),
+            chars.iter().any(|c| !c.is_ascii_alphanumeric()),
+        ]
+        .into_iter()
+        .filter(|&class| class)
+        .count();
+        let penalty = usize::from(has_repetition(&chars)) + usize::from(has_sequence(&chars));
+
+        let score = (length + classes - 1).saturating_sub(penalty);
+        let max = match chars.len() {
+            0..=7 => 1,
+            8..=11 => 3,
+            _ => 4,
+        };
+        Self::from_score(score.min(max))
+    }
+
+    /// The strength for the given score, from 0 (very weak) to 4 (very strong).
+    ///
+    /// Scores above 4 are very strong.
+    #[must_use]
+    pub const fn from_score(score: usize) -> Self {
+        match score {
+            0 => Self::VeryWeak,
+            1 => Self::Weak,
+            2 => Self::Fair,
+            3 => Self::Strong,
+            _ => Self::VeryStrong,
+        }
+    }
+
+    /// The score of the strength, from 0 (very weak) to 4 (very strong).
+    #[must_use]
+    pub const fn score(self) -> usize {
+        self as usize
+    }
+
+    /// A label describing the strength.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::VeryWeak => "very weak",
+            Self::Weak => "weak",
+            Self::Fair => "fair",
+            Self::Strong => "strong",
+            Self::VeryStrong => "very strong",
+        }
+    }
+}
+
+/// Whether the password contains a character repeated three times in a row, or consists of a
+/// repeated substring.
+fn has_repetition(chars: &[char]) -> bool {
+    let repeated_char = chars.windows(3).any(|w| w[0] == w[1] && w[1] == w[2]);
+    let repeated_substring = (1..=chars.len() / 2)
+        .filter(|len| chars.len() % len == 0)
+        .any(|len| chars.chunks(len).all_equal());
+    repeated_char || repeated_substring
+}
+
+/// Whether the password contains four consecutive ascending or descending characters.
+fn has_sequence(chars: &[char]) -> bool {
+    chars.windows(4).any(|w| {
+        let steps = || {
+            w.iter()
+                .tuple_windows()
+                .map(|(&a, &b)| i64::from(u32::from(b)) - i64::from(u32::from(a)))
+        };
+        steps().all(|step| step == 1) || steps().all(|step| step == -1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty("", PasswordStrength::VeryWeak)]
+    #[case::common("password", PasswordStrength::VeryWeak)]
+    #[case::common_uppercase("Password", PasswordStrength::VeryWeak)]
+    #[case::short("hunter2", PasswordStrength::Weak)]
+    #[case::short_single_class("abc", PasswordStrength::VeryWeak)]
+    #[case::lowercase("correcthorse", PasswordStrength::Fair)]
+    #[case::two_classes("correcthorse7", PasswordStrength::Strong)]
+    #[case::repeated_char("aaaaaaaaaaaa", PasswordStrength::Weak)]
+    #[case::repeated_substring("ab1ab1ab1ab1", PasswordStrength::Fair)]
+    #[case::sequence("abcdEFGH1234", PasswordStrength::Strong)]
+    #[case::four_classes("Tr0ub4dor&3", PasswordStrength::Strong)]
+    #[case::passphrase("correct horse battery staple", PasswordStrength::VeryStrong)]
+    fn estimate(#[case] password: &str, #[case] expected: PasswordStrength) {
+        assert_eq!(PasswordStrength::estimate(password), expected);
+    }
+
+    #[test]
+    fn score() {
+        for (score, strength) in PasswordStrength::ALL.into_iter().enumerate() {
+            assert_eq!(strength.score(), score);
+            assert_eq!(PasswordStrength::from_score(score), strength);
+        }
+        assert_eq!(
+            PasswordStrength::from_score(99),
+            PasswordStrength::VeryStrong
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(PasswordStrength::Weak < PasswordStrength::Fair);
+        assert!(PasswordStrength::VeryStrong > PasswordStrength::Strong);
+    }
+}
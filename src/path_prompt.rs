@@ -0,0 +1,154 @@
+use std::borrow::Cow;
+
+use crate::prelude::*;
+
+use ratatui::{
+    prelude::*,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A prompt widget that asks for a filesystem path.
+///
+/// The input is rendered like a [`TextPrompt`], including the listed completions. When the path is
+/// invalid, the error is rendered on the last line of the area. See [`PathState`] for the behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPrompt<'a> {
+    /// The message to display before the path.
+    message: Cow<'a, str>,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
+}
+
+impl<'a> PathPrompt<'a> {
+    #[must_use]
+    pub const fn new(message: Cow<'a, str>) -> Self {
+        Self {
+            message,
+            theme: Theme::new(),
+        }
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Prompt for PathPrompt<'_> {
+    /// Draws the prompt widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if state.is_focused() {
+            frame.set_cursor(state.cursor().0, state.cursor().1);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for PathPrompt<'a> {
+    type State = PathState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let error = state.error();
+        let error_height = u16::from(error.is_some() && area.height > 1);
+        let [input_area, error_area] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(error_height)]).areas(area);
+
+        let input_prompt = TextPrompt::new(self.message).with_theme(self.theme.clone());
+        input_prompt.render(input_area, buf, state.input_mut());
+
+        if let Some(error) = error.filter(|_| !error_area.is_empty()) {
+            Span::styled(error, self.theme.error).render(error_area, buf);
+        }
+    }
+}
+
+impl<T> From<T> for PathPrompt<'static>
+where
+    T: Into<Cow<'static, str>>,
+{
+    fn from(message: T) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+
+    fn render<'a>(prompt: PathPrompt<'a>, state: &mut PathState<'a>) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 3));
+        prompt.render(buffer.area, &mut buffer, state);
+        buffer.set_style(buffer.area, Style::reset());
+        buffer
+    }
+
+    #[test]
+    fn new() {
+        let prompt = PathPrompt::from("Path");
+        assert_eq!(prompt.message, "Path");
+        assert_eq!(prompt.theme, Theme::default());
+    }
+
+    #[test]
+    fn render_completions() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("docs")).unwrap();
+        fs::write(dir.path().join("deny.toml"), "").unwrap();
+        let prompt = PathPrompt::from("Path");
+        let mut state = PathState::new().with_base_dir(dir.path());
+        state.push('d');
+        state.handle_key_event(KeyCode::Tab.into());
+
+        assert_eq!(
+            render(prompt, &mut state),
+            Buffer::with_lines([
+                "? Path › d                    ",
+                "  deny.toml                   ",
+                "  docs/                       ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let prompt = PathPrompt::from("Path");
+        let mut state = PathState::new()
+            .with_base_dir(dir.path())
+            .with_existence(PathExistence::Existing)
+            .with_value("missing");
+        state.handle_key_event(KeyCode::Enter.into());
+
+        assert_eq!(
+            render(prompt, &mut state),
+            Buffer::with_lines([
+                "? Path › missing              ",
+                "                              ",
+                "No such file or directory     ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_error_no_room() {
+        let prompt = PathPrompt::from("Path");
+        let mut state = PathState::new();
+        state.handle_key_event(KeyCode::Enter.into());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+        buffer.set_style(buffer.area, Style::reset());
+
+        assert_eq!(buffer, Buffer::with_lines(["? Path ›            "]));
+    }
+}
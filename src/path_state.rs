@@ -0,0 +1,561 @@
+use std::{
+    borrow::Cow,
+    env, fs,
+    path::{Path, PathBuf, MAIN_SEPARATOR},
+};
+
+use itertools::Itertools;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+use crate::{prelude::*, Completer, State};
+
+/// The kind of filesystem entry a [`PathState`] accepts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathKind {
+    /// Files and directories.
+    #[default]
+    Any,
+    /// Files only. Directories are still completed so that they can be navigated.
+    File,
+    /// Directories only.
+    Directory,
+}
+
+/// Whether a [`PathState`] requires the path to exist.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathExistence {
+    /// The path may or may not exist.
+    #[default]
+    Any,
+    /// The path must exist, e.g. for a file to open.
+    Existing,
+    /// The path must not exist, e.g. for a file to create.
+    NotExisting,
+}
+
+/// A [`Completer`] that completes the last component of a path from the entries of its directory.
+///
+/// Directories are completed with a trailing `/`, and a leading `~` is expanded to the home
+/// directory. Hidden entries are only completed when the component starts with a `.`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct PathCompleter {
+    base_dir: Option<PathBuf>,
+    kind: PathKind,
+}
+
+impl PathCompleter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            base_dir: None,
+            kind: PathKind::Any,
+        }
+    }
+
+    /// Sets the directory that relative paths are completed from.
+    ///
+    /// Defaults to the current directory.
+    #[must_use]
+    pub fn with_base_dir(mut self, base_dir: impl Into<PathBuf>) -> Self {
+        self.base_dir = Some(base_dir.into());
+        self
+    }
+
+    /// Only completes directories when the kind is [`PathKind::Directory`].
+    #[must_use]
+    pub const fn with_kind(mut self, kind: PathKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+impl Completer for PathCompleter {
+    fn complete(&self, value: &str, position: usize) -> Vec<String> {
+        let before_cursor: String = value.chars().take(position).collect();
+        let (dir, prefix) = before_cursor
+            .rfind(is_separator)
+            .map_or(("", before_cursor.as_str()), |index| {
+                before_cursor.split_at(index + 1)
+            });
+        let dir_path = resolve(
+            self.base_dir.as_deref(),
+            if dir.is_empty() { "." } else { dir },
+        );
+        let Ok(entries) = fs::read_dir(dir_path) else {
+            return Vec::new();
+        };
+        entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.'))
+                {
+                    return None;
+                }
+                // follow symlinks so that links to directories complete like directories
+                let is_dir = entry.path().is_dir();
+                if self.kind == PathKind::Directory && !is_dir {
+                    return None;
+                }
+                let separator = if is_dir { "/" } else { "" };
+                Some(format!("{dir}{name}{separator}"))
+            })
+            .sorted()
+            .collect()
+    }
+}
+
+/// The state of a [`PathPrompt`].
+///
+/// Tab completes the path from the filesystem (see [`PathCompleter`]). When Enter is pressed, the
+/// path is validated against the [`PathKind`] and [`PathExistence`] restrictions, and the prompt
+/// only becomes [`Status::Done`] if it is valid. Otherwise an error is shown until the next key
+/// press.
+///
+/// A leading `~` is expanded to the home directory in [`PathState::path`], but kept in the value.
+/// Keybindings are those of [`TextState`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathState<'a> {
+    status: Status,
+    focus: FocusState,
+    input: TextState<'a>,
+    base_dir: Option<PathBuf>,
+    kind: PathKind,
+    existence: PathExistence,
+    error: Option<&'static str>,
+}
+
+impl Default for PathState<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> PathState<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        let state = Self {
+            status: Status::Pending,
+            focus: FocusState::Unfocused,
+            input: TextState::new(),
+            base_dir: None,
+            kind: PathKind::Any,
+            existence: PathExistence::Any,
+            error: None,
+        };
+        state.with_completer()
+    }
+
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_focus(mut self, focus: FocusState) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<Cow<'a, str>>) -> Self {
+        self.input = self.input.with_value(value);
+        self.input.move_end();
+        self
+    }
+
+    /// Sets the default path, used when the prompt is completed with an empty input.
+    #[must_use]
+    pub fn with_default(mut self, default: impl Into<Cow<'a, str>>) -> Self {
+        self.input = self.input.with_default(default);
+        self
+    }
+
+    /// Sets the directory that relative paths are completed and validated from.
+    ///
+    /// Defaults to the current directory.
+    #[must_use]
+    pub fn with_base_dir(mut self, base_dir: impl Into<PathBuf>) -> Self {
+        self.base_dir = Some(base_dir.into());
+        self.with_completer()
+    }
+
+    /// Restricts the path to files or directories.
+    #[must_use]
+    pub fn with_kind(mut self, kind: PathKind) -> Self {
+        self.kind = kind;
+        self.with_completer()
+    }
+
+    /// Requires the path to exist or not to exist.
+    #[must_use]
+    pub const fn with_existence(mut self, existence: PathExistence) -> Self {
+        self.existence = existence;
+        self
+    }
+
+    /// The path entered, with a leading `~` expanded to the home directory.
+    ///
+    /// Relative paths are joined to the base directory, if one is set.
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        resolve(self.base_dir.as_deref(), self.input.value())
+    }
+
+    /// The state of the text input.
+    #[must_use]
+    pub const fn input(&self) -> &TextState<'a> {
+        &self.input
+    }
+
+    /// A mutable reference to the state of the text input.
+    pub fn input_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.input
+    }
+
+    /// The error of the last validation, if the path was invalid.
+    #[must_use]
+    pub const fn error(&self) -> Option<&'static str> {
+        self.error
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    /// Checks the path against the restrictions, returning an error message if it is invalid.
+    fn validate(&self) -> Result<(), &'static str> {
+        if self.input.is_empty() {
+            return Err("Enter a path");
+        }
+        let path = self.path();
+        let exists = path.exists();
+        match self.existence {
+            PathExistence::Existing if !exists => return Err("No such file or directory"),
+            PathExistence::NotExisting if exists => return Err("Path already exists"),
+            _ => {}
+        }
+        match self.kind {
+            PathKind::File if path.is_dir() => Err("Path is a directory"),
+            PathKind::File if exists && !path.is_file() => Err("Path is not a file"),
+            PathKind::Directory if exists && !path.is_dir() => Err("Path is not a directory"),
+            _ => Ok(()),
+        }
+    }
+
+    fn with_completer(mut self) -> Self {
+        let mut completer = PathCompleter::new().with_kind(self.kind);
+        if let Some(base_dir) = &self.base_dir {
+            completer = completer.with_base_dir(base_dir);
+        }
+        self.input = std::mem::take(&mut self.input).with_completer(completer);
+        self
+    }
+}
+
+impl State for PathState<'_> {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn status_mut(&mut self) -> &mut Status {
+        &mut self.status
+    }
+
+    fn focus_state_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    fn focus_state(&self) -> FocusState {
+        self.focus
+    }
+
+    fn position(&self) -> usize {
+        self.input.position()
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        self.input.position_mut()
+    }
+
+    fn cursor(&self) -> (u16, u16) {
+        self.input.cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut (u16, u16) {
+        self.input.cursor_mut()
+    }
+
+    fn value(&self) -> &str {
+        self.input.value()
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        self.input.value_mut()
+    }
+
+    fn has_default(&self) -> bool {
+        self.input.has_default()
+    }
+
+    fn captures_key(&self, key_event: KeyEvent) -> bool {
+        self.input.captures_key(key_event)
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        if key_event.code == KeyCode::Enter {
+            self.complete();
+            return;
+        }
+        self.error = None;
+        self.input.handle_key_event(key_event);
+        if self.input.status().is_aborted() {
+            self.status = Status::Aborted;
+        }
+    }
+
+    fn complete(&mut self) {
+        self.input.clear_completions();
+        self.input.complete();
+        if !self.input.status().is_done() {
+            return;
+        }
+        if let Err(error) = self.validate() {
+            *self.input.status_mut() = Status::Pending;
+            self.error = Some(error);
+            return;
+        }
+        self.status = Status::Done;
+    }
+
+    fn abort(&mut self) {
+        self.input.abort();
+        self.status = Status::Aborted;
+    }
+
+    fn delete(&mut self) {
+        self.input.delete();
+    }
+
+    fn backspace(&mut self) {
+        self.input.backspace();
+    }
+
+    fn kill(&mut self) {
+        self.input.kill();
+    }
+
+    fn truncate(&mut self) {
+        self.input.truncate();
+    }
+
+    fn push(&mut self, c: char) {
+        self.input.push(c);
+    }
+}
+
+/// Expands a leading `~` and joins relative paths to the base directory.
+fn resolve(base_dir: Option<&Path>, path: &str) -> PathBuf {
+    let path = expand_tilde(path, home_dir().as_deref());
+    match base_dir {
+        Some(base_dir) if path.is_relative() => base_dir.join(path),
+        _ => path,
+    }
+}
+
+/// Replaces a leading `~` with the home directory, if it is known.
+fn expand_tilde(path: &str, home: Option<&Path>) -> PathBuf {
+    match (path.strip_prefix('~'), home) {
+        (Some(""), Some(home)) => home.to_path_buf(),
+        (Some(rest), Some(home)) if rest.starts_with(is_separator) => {
+            home.join(rest.trim_start_matches(is_separator))
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home)).filter(|home| !home.as_os_str().is_empty())
+}
+
+const fn is_separator(c: char) -> bool {
+    c == '/' || c == MAIN_SEPARATOR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{temp_dir, type_str};
+
+    #[test]
+    fn complete_entries() {
+        let dir = temp_dir();
+        let completer = PathCompleter::new().with_base_dir(dir.path());
+        assert_eq!(
+            completer.complete("", 0),
+            ["Cargo.toml", "README.md", "src/"]
+        );
+        assert_eq!(completer.complete("s", 1), ["src/"]);
+        assert_eq!(completer.complete("src/", 4), ["src/lib.rs", "src/main.rs"]);
+        assert_eq!(completer.complete(".", 1), [".git/", ".hidden"]);
+        assert!(completer.complete("missing/", 8).is_empty());
+    }
+
+    #[test]
+    fn complete_directories() {
+        let dir = temp_dir();
+        let completer = PathCompleter::new()
+            .with_base_dir(dir.path())
+            .with_kind(PathKind::Directory);
+        assert_eq!(completer.complete("", 0), ["src/"]);
+    }
+
+    #[test]
+    fn complete_absolute() {
+        let dir = temp_dir();
+        let value = format!("{}/s", dir.path().display());
+        let completer = PathCompleter::new();
+        assert_eq!(
+            completer.complete(&value, value.chars().count()),
+            [format!("{}/src/", dir.path().display())]
+        );
+    }
+
+    #[test]
+    fn tab() {
+        let dir = temp_dir();
+        let mut state = PathState::new().with_base_dir(dir.path());
+        type_str(&mut state, "s");
+        state.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(state.value(), "src/");
+        type_str(&mut state, "l");
+        state.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(state.value(), "src/lib.rs");
+    }
+
+    #[test]
+    fn expand_home() {
+        let home = Path::new("/home/user");
+        assert_eq!(expand_tilde("~", Some(home)), home);
+        assert_eq!(expand_tilde("~/src", Some(home)), home.join("src"));
+        assert_eq!(
+            expand_tilde("~user/src", Some(home)),
+            Path::new("~user/src")
+        );
+        assert_eq!(expand_tilde("src/~", Some(home)), Path::new("src/~"));
+        assert_eq!(expand_tilde("~/src", None), Path::new("~/src"));
+    }
+
+    #[test]
+    fn path() {
+        let dir = temp_dir();
+        let state = PathState::new()
+            .with_base_dir(dir.path())
+            .with_value("src/lib.rs");
+        assert_eq!(state.path(), dir.path().join("src/lib.rs"));
+    }
+
+    #[test]
+    fn complete_valid() {
+        let dir = temp_dir();
+        let mut state = PathState::new().with_base_dir(dir.path());
+        type_str(&mut state, "Cargo.toml");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.error(), None);
+    }
+
+    #[test]
+    fn complete_empty() {
+        let mut state = PathState::new();
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.error(), Some("Enter a path"));
+    }
+
+    #[test]
+    fn complete_default() {
+        let dir = temp_dir();
+        let mut state = PathState::new()
+            .with_base_dir(dir.path())
+            .with_default("src");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.value(), "src");
+    }
+
+    #[test]
+    fn existing() {
+        let dir = temp_dir();
+        let mut state = PathState::new()
+            .with_base_dir(dir.path())
+            .with_existence(PathExistence::Existing);
+        type_str(&mut state, "missing");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.input().status(), Status::Pending);
+        assert_eq!(state.error(), Some("No such file or directory"));
+
+        state.handle_key_event(KeyCode::Char('x').into());
+        assert_eq!(state.error(), None);
+    }
+
+    #[test]
+    fn not_existing() {
+        let dir = temp_dir();
+        let mut state = PathState::new()
+            .with_base_dir(dir.path())
+            .with_existence(PathExistence::NotExisting);
+        type_str(&mut state, "src");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.error(), Some("Path already exists"));
+
+        type_str(&mut state, "2");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+    }
+
+    #[test]
+    fn file() {
+        let dir = temp_dir();
+        let mut state = PathState::new()
+            .with_base_dir(dir.path())
+            .with_kind(PathKind::File);
+        type_str(&mut state, "src");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.error(), Some("Path is a directory"));
+
+        type_str(&mut state, "/lib.rs");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+    }
+
+    #[test]
+    fn directory() {
+        let dir = temp_dir();
+        let mut state = PathState::new()
+            .with_base_dir(dir.path())
+            .with_kind(PathKind::Directory);
+        type_str(&mut state, "Cargo.toml");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.error(), Some("Path is not a directory"));
+
+        state.truncate();
+        type_str(&mut state, "new");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+    }
+
+    #[test]
+    fn abort() {
+        let mut state = PathState::new();
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+    }
+}
@@ -0,0 +1,277 @@
+#[cfg(any(feature = "json", feature = "toml"))]
+use std::io;
+use std::{env, fmt};
+
+/// Answers that pre-fill the questions of a [`QuestionnaireState`] by name, e.g. to run a setup
+/// wizard from an answer file or environment variables in a scripted deployment.
+///
+/// A pre-filled value is typed into the question when it is asked, so it goes through the same
+/// validation and parsing as a typed answer: a confirm question takes `yes` / `no` (or `true` /
+/// `false` in an answer file), and a select question takes the text that selects the choice. The
+/// question is then submitted and not shown, unless the value is rejected or the pre-fill is
+/// [reviewed](Prefill::with_review), in which case the question is asked with the value filled in.
+///
+/// Values are kept in the order they are added, and a later value for a name replaces an earlier
+/// one.
+///
+/// ```
+/// use tui_prompts::prelude::*;
+///
+/// let prefill = Prefill::new()
+///     .with_value("name", "Ada")
+///     .merge(Prefill::from_env("SETUP_"));
+/// let state = QuestionnaireState::new()
+///     .with_question(Question::new("name", TextPrompt::from("Name"), TextState::new()))
+///     .with_prefill(prefill);
+/// ```
+///
+/// [`QuestionnaireState`]: crate::QuestionnaireState
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Prefill {
+    values: Vec<(String, String)>,
+    review: bool,
+}
+
+impl Prefill {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            review: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_value(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values.push((name.into(), value.into()));
+        self
+    }
+
+    /// Shows the pre-filled questions with their values filled in for review, rather than
+    /// submitting them.
+    ///
+    /// A question that is answered by a single key, e.g. a confirm question, is answered by its
+    /// value either way.
+    #[must_use]
+    pub const fn with_review(mut self, review: bool) -> Self {
+        self.review = review;
+        self
+    }
+
+    #[must_use]
+    pub const fn is_review(&self) -> bool {
+        self.review
+    }
+
+    /// Adds the values of `other`, which replace the values of the same names.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        self.values.extend(other.values);
+        self
+    }
+
+    /// The value of the question with the name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The values of the environment variables that start with the prefix, named after the rest
+    /// of the variable in lowercase, e.g. `SETUP_PROJECT_NAME` with the prefix `SETUP_` pre-fills
+    /// the question `project_name`.
+    #[must_use]
+    pub fn from_env(prefix: &str) -> Self {
+        Self::from_vars(prefix, env::vars())
+    }
+
+    fn from_vars(prefix: &str, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut prefill = Self::new();
+        for (key, value) in vars {
+            if let Some(name) = key.strip_prefix(prefix) {
+                prefill = prefill.with_value(name.to_lowercase(), value);
+            }
+        }
+        prefill
+    }
+
+    /// The values of a JSON object of question names to strings, numbers or booleans (requires the
+    /// `json` feature). Null values are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the JSON is not such an object.
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        use serde_json::Value;
+
+        let object: serde_json::Map<String, Value> = serde_json::from_str(json)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let mut prefill = Self::new();
+        for (name, value) in object {
+            let value = match value {
+                Value::Null => continue,
+                Value::String(value) => value,
+                Value::Bool(value) => yes_no(value),
+                Value::Number(value) => value.to_string(),
+                Value::Array(_) | Value::Object(_) => return Err(unsupported_value(&name)),
+            };
+            prefill = prefill.with_value(name, value);
+        }
+        Ok(prefill)
+    }
+
+    /// The values of a TOML table of question names to strings, numbers, booleans or dates
+    /// (requires the `toml` feature).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the TOML is not such a table.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> io::Result<Self> {
+        use toml::Value;
+
+        let table: toml::Table = toml
+            .parse()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let mut prefill = Self::new();
+        for (name, value) in table {
+            let value = match value {
+                Value::String(value) => value,
+                Value::Boolean(value) => yes_no(value),
+                Value::Integer(value) => value.to_string(),
+                Value::Float(value) => value.to_string(),
+                Value::Datetime(value) => value.to_string(),
+                Value::Array(_) | Value::Table(_) => return Err(unsupported_value(&name)),
+            };
+            prefill = prefill.with_value(name, value);
+        }
+        Ok(prefill)
+    }
+
+    /// The values of an answer file, which is read as JSON or TOML by its extension (requires the
+    /// `json` or `toml` feature).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, its extension is not supported, or its
+    /// content is invalid, see [`Prefill::from_json`] and [`Prefill::from_toml`].
+    #[cfg(any(feature = "json", feature = "toml"))]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            #[cfg(feature = "json")]
+            Some("json") => Self::from_json(&content),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&content),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported answer file: {}", path.display()),
+            )),
+        }
+    }
+}
+
+impl fmt::Debug for Prefill {
+    /// The values are left out as they may be secret.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<_> = self.values.iter().map(|(name, _)| name).collect();
+        f.debug_struct("Prefill")
+            .field("names", &names)
+            .field("review", &self.review)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+fn yes_no(value: bool) -> String {
+    if value { "yes" } else { "no" }.to_string()
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+fn unsupported_value(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("the answer to `{name}` is not a string, number or boolean"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn later_values_replace_earlier_values() {
+        let prefill = Prefill::new()
+            .with_value("name", "Ada")
+            .merge(Prefill::new().with_value("name", "Grace"));
+        assert_eq!(prefill.get("name"), Some("Grace"));
+        assert_eq!(prefill.get("email"), None);
+    }
+
+    #[test]
+    fn from_vars() {
+        let vars = [
+            ("SETUP_PROJECT_NAME".to_string(), "tui".to_string()),
+            ("HOME".to_string(), "/root".to_string()),
+        ];
+        let prefill = Prefill::from_vars("SETUP_", vars);
+        assert_eq!(prefill.get("project_name"), Some("tui"));
+        assert_eq!(prefill.get("home"), None);
+    }
+
+    #[test]
+    fn debug_leaves_out_values() {
+        let prefill = Prefill::new().with_value("token", "secret");
+        assert!(!format!("{prefill:?}").contains("secret"));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn from_json() {
+        let prefill =
+            Prefill::from_json(r#"{"name": "Ada", "git": true, "port": 8080, "email": null}"#)
+                .unwrap();
+        assert_eq!(prefill.get("name"), Some("Ada"));
+        assert_eq!(prefill.get("git"), Some("yes"));
+        assert_eq!(prefill.get("port"), Some("8080"));
+        assert_eq!(prefill.get("email"), None);
+        let err = Prefill::from_json(r#"{"tags": ["a"]}"#).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(Prefill::from_json("[]").is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml() {
+        let prefill = Prefill::from_toml("name = \"Ada\"\ngit = false\nport = 8080").unwrap();
+        assert_eq!(prefill.get("name"), Some("Ada"));
+        assert_eq!(prefill.get("git"), Some("no"));
+        assert_eq!(prefill.get("port"), Some("8080"));
+        let err = Prefill::from_toml("[server]\nport = 1").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(all(feature = "json", feature = "toml"))]
+    #[test]
+    fn from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let json = dir.path().join("answers.json");
+        std::fs::write(&json, r#"{"name": "Ada"}"#).unwrap();
+        assert_eq!(Prefill::from_file(&json).unwrap().get("name"), Some("Ada"));
+        let toml = dir.path().join("answers.toml");
+        std::fs::write(&toml, "name = \"Grace\"").unwrap();
+        assert_eq!(
+            Prefill::from_file(&toml).unwrap().get("name"),
+            Some("Grace")
+        );
+        let yaml = dir.path().join("answers.yaml");
+        std::fs::write(&yaml, "name: Ada").unwrap();
+        let err = Prefill::from_file(&yaml).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
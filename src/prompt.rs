@@ -95,26 +95,44 @@ pub trait State {
         self.value().len() == 0
     }
 
+    /// Whether completing the prompt with an empty input answers it, e.g. with a default value.
+    ///
+    /// An empty line or the end of the input of a [`NonInteractive`](crate::NonInteractive) runner
+    /// only answers a prompt that is not empty or has a default.
+    fn has_default(&self) -> bool {
+        false
+    }
+
+    /// The step of a prompt that is answered in several steps, e.g. the confirmation of a password.
+    ///
+    /// A [`NonInteractive`](crate::NonInteractive) runner reads another line for the prompt when
+    /// Enter moves it to another step rather than finishing it.
+    fn step(&self) -> usize {
+        0
+    }
+
+    /// Whether the prompt handles a key that a container of prompts binds otherwise, e.g. Tab for
+    /// completion, which a [`FormState`](crate::FormState) then passes to the prompt.
+    fn captures_key(&self, _key_event: KeyEvent) -> bool {
+        false
+    }
+
     fn handle_key_event(&mut self, key_event: KeyEvent) {
-        if key_event.kind == KeyEventKind::Release {
-            return;
-        }
+        handle_key_event(self, key_event);
+    }
 
-        match (key_event.code, key_event.modifiers) {
-            (KeyCode::Enter, _) => self.complete(),
-            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => self.abort(),
-            (KeyCode::Left, _) | (KeyCode::Char('b'), KeyModifiers::CONTROL) => self.move_left(),
-            (KeyCode::Right, _) | (KeyCode::Char('f'), KeyModifiers::CONTROL) => self.move_right(),
-            (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => self.move_start(),
-            (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => self.move_end(),
-            (KeyCode::Backspace, _) | (KeyCode::Char('h'), KeyModifiers::CONTROL) => {
-                self.backspace();
+    /// Handles text pasted into the prompt, e.g. from [`Event::Paste`].
+    ///
+    /// By default, the characters are typed one after another while the prompt is pending, and
+    /// line breaks and other control characters are dropped.
+    ///
+    /// [`Event::Paste`]: ratatui::crossterm::event::Event::Paste
+    fn handle_paste(&mut self, text: &str) {
+        for c in text.chars().filter(|c| !c.is_control()) {
+            if !self.status().is_pending() {
+                break;
             }
-            (KeyCode::Delete, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => self.delete(),
-            (KeyCode::Char('k'), KeyModifiers::CONTROL) => self.kill(),
-            (KeyCode::Char('u'), KeyModifiers::CONTROL) => self.truncate(),
-            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => self.push(c),
-            _ => {}
+            self.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
         }
     }
 
@@ -198,6 +216,34 @@ pub trait State {
     }
 }
 
+/// Handles a key event using the default keybindings of [`State`].
+///
+/// States that override [`State::handle_key_event`] to add their own keybindings call this to fall
+/// back to the defaults.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn handle_key_event<S: State + ?Sized>(state: &mut S, key_event: KeyEvent) {
+    if key_event.kind == KeyEventKind::Release {
+        return;
+    }
+
+    match (key_event.code, key_event.modifiers) {
+        (KeyCode::Enter, _) => state.complete(),
+        (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => state.abort(),
+        (KeyCode::Left, _) | (KeyCode::Char('b'), KeyModifiers::CONTROL) => state.move_left(),
+        (KeyCode::Right, _) | (KeyCode::Char('f'), KeyModifiers::CONTROL) => state.move_right(),
+        (KeyCode::Home, _) | (KeyCode::Char('a'), KeyModifiers::CONTROL) => state.move_start(),
+        (KeyCode::End, _) | (KeyCode::Char('e'), KeyModifiers::CONTROL) => state.move_end(),
+        (KeyCode::Backspace, _) | (KeyCode::Char('h'), KeyModifiers::CONTROL) => {
+            state.backspace();
+        }
+        (KeyCode::Delete, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => state.delete(),
+        (KeyCode::Char('k'), KeyModifiers::CONTROL) => state.kill(),
+        (KeyCode::Char('u'), KeyModifiers::CONTROL) => state.truncate(),
+        (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => state.push(c),
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
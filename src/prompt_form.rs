@@ -0,0 +1,357 @@
+use std::{borrow::Cow, io};
+
+use crate::{prelude::*, text_state::FnRef, Answer, Answers, Question, Secret, Validator};
+
+/// A struct whose fields are asked as the questions of a questionnaire.
+///
+/// This is usually derived with `#[derive(Prompt)]` (requires the `derive` feature), which asks a
+/// question for each field, named after the field:
+///
+/// - `String`: a [`TextPrompt`]
+/// - `bool`: a [`ConfirmPrompt`]
+/// - an enum that also derives `Prompt`: an [`AutocompletePrompt`] of the variants
+/// - `Option<String>`: a [`TextPrompt`], where an empty answer is `None`
+///
+/// Fields accept the `#[prompt(...)]` attribute:
+///
+/// - `message = "..."`: the message of the prompt, defaults to the name of the field
+/// - `default = "..."`: the default answer, which is `yes`, `no`, `true` or `false` for a `bool`
+/// - `validate = path::to::fn`: a `fn(&str) -> Result<(), String>` that checks the answer of a
+///   text field, see [`AskText`]
+/// - `password`: renders the input of a text field as a password and keeps the value secret
+///
+/// ```no_run
+/// # #[cfg(feature = "derive")]
+/// # fn main() -> std::io::Result<()> {
+/// use tui_prompts::{Prompt, PromptForm, Theme};
+///
+/// fn not_empty(value: &str) -> Result<(), String> {
+///     if value.is_empty() {
+///         Err("must not be empty".to_string())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Prompt)]
+/// enum License {
+///     Mit,
+///     Apache,
+/// }
+///
+/// #[derive(Prompt)]
+/// struct Project {
+///     #[prompt(message = "Project name", validate = not_empty)]
+///     name: String,
+///     description: Option<String>,
+///     license: License,
+///     #[prompt(default = "yes")]
+///     git: bool,
+/// }
+///
+/// let project = Project::prompt(&Theme::from_env())?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "derive"))]
+/// # fn main() {}
+/// ```
+pub trait PromptForm: Sized {
+    /// The questionnaire that asks the fields of the struct.
+    fn questionnaire(theme: &Theme) -> QuestionnaireState<'static>;
+
+    /// Builds the struct from the answers of the questionnaire, if every field was answered.
+    fn from_answers(answers: &Answers) -> Option<Self>;
+
+    /// Asks the questions inline below the cursor on stderr until the questionnaire is finished,
+    /// returning the struct, or `None` if a question was aborted.
+    ///
+    /// If stdin is not a terminal, the questions are answered from lines of stdin instead. See
+    /// [`InlineRunner::run_questionnaire`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the terminal cannot be set up, drawn to or read from, or if the lines
+    /// read from stdin do not answer the questions.
+    fn prompt(theme: &Theme) -> io::Result<Option<Self>> {
+        Self::prompt_prefilled(theme, Prefill::new())
+    }
+
+    /// Asks the questions like [`PromptForm::prompt`], pre-filling the fields with the values of
+    /// the same names, e.g. from an answer file or environment variables, see [`Prefill`].
+    ///
+    /// # Errors
+    ///
+    /// See [`PromptForm::prompt`].
+    fn prompt_prefilled(theme: &Theme, prefill: Prefill) -> io::Result<Option<Self>> {
+        let state = Self::questionnaire(theme).with_prefill(prefill);
+        let answers = InlineRunner::stderr()?.run_questionnaire(state)?;
+        Ok(answers.and_then(|answers| Self::from_answers(&answers)))
+    }
+}
+
+/// A type that is asked with a question of a [`PromptForm`].
+pub trait Ask: FromAnswer {
+    /// The question that asks for a value of the type.
+    fn question(name: &'static str, options: AskOptions) -> Question<'static>;
+}
+
+/// A type that is asked with a [`TextPrompt`], which accepts the `validate` and `password` options
+/// of `#[derive(Prompt)]`. The derive rejects these options on fields of other types.
+pub trait AskText: Ask {}
+
+/// A type that is converted from the [`Answer`] of its question.
+pub trait FromAnswer: Sized {
+    fn from_answer(answer: &Answer) -> Option<Self>;
+}
+
+/// An enum that is asked by selecting one of its variants, usually derived with
+/// `#[derive(Prompt)]`.
+pub trait Choice: Sized {
+    /// The names of the variants, in the order they are listed.
+    fn choices() -> &'static [&'static str];
+
+    /// The variant with the name.
+    fn from_choice(choice: &str) -> Option<Self>;
+}
+
+/// The options of the question of a field of a [`PromptForm`], set by the `#[prompt(...)]`
+/// attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AskOptions {
+    message: Cow<'static, str>,
+    default: Option<Cow<'static, str>>,
+    validator: Option<FnRef<Validator>>,
+    password: bool,
+    theme: Theme,
+}
+
+impl AskOptions {
+    #[must_use]
+    pub const fn new(message: Cow<'static, str>) -> Self {
+        Self {
+            message,
+            default: None,
+            validator: None,
+            password: false,
+            theme: Theme::new(),
+        }
+    }
+
+    /// Sets the default answer, e.g. `yes` for a `bool` or the name of a variant for an enum.
+    #[must_use]
+    pub fn with_default(mut self, default: impl Into<Cow<'static, str>>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Sets the validator of a text answer, see [`TextState::with_validator`].
+    #[must_use]
+    pub const fn with_validator(mut self, validator: Validator) -> Self {
+        self.validator = Some(FnRef(validator));
+        self
+    }
+
+    /// Renders a text answer as a password and keeps it secret.
+    #[must_use]
+    pub const fn with_password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// The question of a [`Choice`], listing its variants.
+    #[must_use]
+    pub fn choice_question<T: Choice>(self, name: &'static str) -> Question<'static> {
+        let mut state = AutocompleteState::new(T::choices().iter().copied());
+        if let Some(default) = self.default {
+            state = state.with_value(default);
+        }
+        let prompt = AutocompletePrompt::new(self.message).with_theme(self.theme);
+        Question::new(name, prompt, state)
+    }
+}
+
+impl Ask for String {
+    fn question(name: &'static str, options: AskOptions) -> Question<'static> {
+        let mut prompt = TextPrompt::new(options.message).with_theme(options.theme);
+        let mut state = TextState::new();
+        if let Some(default) = options.default {
+            state = state.with_default(default);
+        }
+        if let Some(FnRef(validator)) = options.validator {
+            state = state.with_validator(validator);
+        }
+        if options.password {
+            prompt = prompt.with_render_style(TextRenderStyle::Password);
+            state = state.with_secret(true);
+        }
+        Question::new(name, prompt, state)
+    }
+}
+
+impl AskText for String {}
+
+impl FromAnswer for String {
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        match answer {
+            Answer::Text(text) => Some(text.clone()),
+            Answer::Secret(secret) => Some(secret.expose_secret().to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl Ask for bool {
+    /// A [`ConfirmPrompt`], where the default is `yes`, `no`, `true` or `false`.
+    fn question(name: &'static str, options: AskOptions) -> Question<'static> {
+        let mut state = ConfirmState::new();
+        match options.default.as_deref() {
+            Some("yes" | "true") => state = state.with_default(true),
+            Some("no" | "false") => state = state.with_default(false),
+            _ => {}
+        }
+        let prompt = ConfirmPrompt::new(options.message).with_theme(options.theme);
+        Question::new(name, prompt, state)
+    }
+}
+
+impl FromAnswer for bool {
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        answer.as_bool()
+    }
+}
+
+/// Only text types are optional, as the prompts of other types cannot be answered with `None`.
+impl<T: AskText> Ask for Option<T> {
+    /// The question of `T`, where the default is empty unless set, so that an empty answer is
+    /// `None`.
+    fn question(name: &'static str, mut options: AskOptions) -> Question<'static> {
+        options.default.get_or_insert(Cow::Borrowed(""));
+        T::question(name, options)
+    }
+}
+
+impl<T: AskText> AskText for Option<T> {}
+
+impl<T: FromAnswer> FromAnswer for Option<T> {
+    /// `None` for an empty text answer.
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        let empty = match answer {
+            Answer::Text(text) => text.is_empty(),
+            Answer::Secret(secret) => secret.is_empty(),
+            _ => false,
+        };
+        if empty {
+            Some(None)
+        } else {
+            T::from_answer(answer).map(Some)
+        }
+    }
+}
+
+impl FromAnswer for Secret {
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        answer
+            .as_secret()
+            .map(|secret| Self::from(secret.expose_secret().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    impl Choice for Color {
+        fn choices() -> &'static [&'static str] {
+            &["Red", "Green"]
+        }
+
+        fn from_choice(choice: &str) -> Option<Self> {
+            match choice {
+                "Red" => Some(Self::Red),
+                "Green" => Some(Self::Green),
+                _ => None,
+            }
+        }
+    }
+
+    fn answer(mut question: Question<'static>, keys: &[KeyCode]) -> Answer {
+        let state = question.state_mut().unwrap();
+        state.focus();
+        for key in keys {
+            state.handle_key_event((*key).into());
+        }
+        assert!(question.is_finished());
+        question.answer().unwrap()
+    }
+
+    #[test]
+    fn string_question() {
+        let options = AskOptions::new("Name".into()).with_default("Ada");
+        let answer = answer(String::question("name", options), &[KeyCode::Enter]);
+        assert_eq!(String::from_answer(&answer).as_deref(), Some("Ada"));
+    }
+
+    #[test]
+    fn password_question() {
+        let options = AskOptions::new("Password".into()).with_password(true);
+        let answer = answer(
+            String::question("password", options),
+            &[KeyCode::Char('x'), KeyCode::Enter],
+        );
+        assert!(answer.as_secret().is_some());
+        assert_eq!(String::from_answer(&answer).as_deref(), Some("x"));
+    }
+
+    #[test]
+    fn bool_question() {
+        let options = AskOptions::new("Continue?".into()).with_default("no");
+        let answer = answer(bool::question("continue", options), &[KeyCode::Enter]);
+        assert_eq!(bool::from_answer(&answer), Some(false));
+    }
+
+    #[test]
+    fn choice_question() {
+        let options = AskOptions::new("Color".into());
+        let answer = answer(
+            options.choice_question::<Color>("color"),
+            &[KeyCode::Down, KeyCode::Enter],
+        );
+        assert_eq!(
+            answer.as_text().and_then(Color::from_choice),
+            Some(Color::Green)
+        );
+    }
+
+    #[test]
+    fn optional() {
+        let options = AskOptions::new("Description".into());
+        let question = Option::<String>::question("description", options);
+        assert!(question.state().unwrap().has_default());
+        let answer = answer(question, &[KeyCode::Enter]);
+        assert_eq!(Option::<String>::from_answer(&answer), Some(None));
+        let empty = Answer::Text(String::new());
+        assert_eq!(Option::<String>::from_answer(&empty), Some(None));
+        let text = Answer::Text("Ada".into());
+        assert_eq!(
+            Option::<String>::from_answer(&text),
+            Some(Some("Ada".into()))
+        );
+        assert_eq!(Option::<bool>::from_answer(&text), None);
+    }
+}
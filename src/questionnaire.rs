@@ -0,0 +1,163 @@
+use std::marker::PhantomData;
+
+use ratatui::{prelude::*, widgets::StatefulWidget};
+
+use crate::{prelude::*, QuestionnaireState};
+
+/// A widget that renders the questions of a [`QuestionnaireState`] that have been asked so far.
+///
+/// Answered questions collapse to a single summary line each, and the current question is rendered
+/// below them in the rest of the area. Questions that have not been asked yet or were skipped are
+/// not rendered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Questionnaire<'a> {
+    /// The lifetime of the questions of the state.
+    _questions: PhantomData<&'a ()>,
+}
+
+impl Questionnaire<'_> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            _questions: PhantomData,
+        }
+    }
+}
+
+impl Prompt for Questionnaire<'_> {
+    /// Draws the questionnaire widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position of the current question.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if let Some(question_state) = state.current_question().and_then(Question::state) {
+            let (x, y) = question_state.cursor();
+            frame.set_cursor(x, y);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for Questionnaire<'a> {
+    type State = QuestionnaireState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let mut area = area;
+        for question in state.asked_mut() {
+            if area.is_empty() {
+                break;
+            }
+            let height = if question.is_finished() {
+                1
+            } else {
+                area.height
+            };
+            let [question_area, rest] =
+                Layout::vertical([Constraint::Length(height), Constraint::Min(0)]).areas(area);
+            question.render(question_area, buf);
+            area = rest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+    use crate::Question;
+
+    fn questionnaire() -> QuestionnaireState<'static> {
+        QuestionnaireState::new()
+            .with_question(Question::new(
+                "name",
+                TextPrompt::from("Name"),
+                TextState::new(),
+            ))
+            .with_question(Question::new(
+                "language",
+                AutocompletePrompt::from("Language"),
+                AutocompleteState::new(["rust", "python"]),
+            ))
+            .with_question(Question::new(
+                "editor",
+                TextPrompt::from("Editor"),
+                TextState::new(),
+            ))
+    }
+
+    fn render(state: &mut QuestionnaireState) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 4));
+        Questionnaire::new().render(buffer.area, &mut buffer, state);
+        buffer.set_style(buffer.area, Style::reset());
+        buffer
+    }
+
+    #[test]
+    fn render_first_question() {
+        let mut state = questionnaire();
+        assert_eq!(
+            render(&mut state),
+            Buffer::with_lines(["? Name ›            ", "", "", ""])
+        );
+    }
+
+    #[test]
+    fn render_summary_lines() {
+        let mut state = questionnaire();
+        state.handle_key_event(KeyCode::Char('A').into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            render(&mut state),
+            Buffer::with_lines([
+                "✔ Name › A          ",
+                "? Language ›        ",
+                "  rust              ",
+                "  python            ",
+            ])
+        );
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            render(&mut state),
+            Buffer::with_lines([
+                "✔ Name › A          ",
+                "✔ Language › rust   ",
+                "? Editor ›          ",
+                "                    ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_summary_of_password_confirm() {
+        let mut state = QuestionnaireState::new()
+            .with_question(Question::new(
+                "password",
+                PasswordConfirmPrompt::from("Password"),
+                PasswordConfirmState::new(),
+            ))
+            .with_question(Question::new(
+                "name",
+                TextPrompt::from("Name"),
+                TextState::new(),
+            ));
+        for key in [KeyCode::Char('x'), KeyCode::Enter, KeyCode::Char('x')] {
+            state.handle_key_event(key.into());
+        }
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            render(&mut state),
+            Buffer::with_lines(["✔ Password › *      ", "? Name ›            ", "", ""])
+        );
+    }
+
+    #[test]
+    fn render_aborted() {
+        let mut state = questionnaire();
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(
+            render(&mut state),
+            Buffer::with_lines(["✘ Name ›            ", "", "", ""])
+        );
+    }
+}
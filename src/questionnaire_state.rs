@@ -0,0 +1,648 @@
+use std::{borrow::Cow, fmt};
+
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent, KeyEventKind},
+    prelude::*,
+};
+
+use crate::{
+    form_state::{Field, PromptField},
+    non_interactive::type_value,
+    prelude::*,
+    Answer, Answers, Prefill, State, ToAnswer,
+};
+
+/// A named question of a [`QuestionnaireState`]: a prompt and its state.
+///
+/// The prompt is cloned each time the question is rendered, so it should be cheap to clone.
+///
+/// A question can depend on the answers to the questions before it: [`Question::with_when`] skips
+/// the question based on the answers, and [`Question::dynamic`] builds the prompt and its state
+/// from the answers when the question is asked, e.g. to compute the message, the default value or
+/// the suggestions.
+pub struct Question<'a> {
+    name: Cow<'a, str>,
+    field: Option<Box<dyn QuestionField + 'a>>,
+    build: Option<BuildFn<'a>>,
+    when: Option<WhenFn<'a>>,
+    skipped: bool,
+    prefilled: bool,
+}
+
+/// Builds the prompt and state of a dynamic question from the previous answers.
+type BuildFn<'a> = Box<dyn FnOnce(&Answers) -> Box<dyn QuestionField + 'a> + 'a>;
+
+/// Whether a question is asked, given the previous answers.
+type WhenFn<'a> = Box<dyn Fn(&Answers) -> bool + 'a>;
+
+/// A [`Field`] with a typed answer.
+trait QuestionField: Field {
+    fn answer(&self) -> Answer;
+}
+
+impl<P, S> QuestionField for PromptField<P, S>
+where
+    P: StatefulWidget<State = S> + Clone,
+    S: State + ToAnswer,
+{
+    fn answer(&self) -> Answer {
+        self.state.to_answer()
+    }
+}
+
+impl<'a> Question<'a> {
+    #[must_use]
+    pub fn new<P, S>(name: impl Into<Cow<'a, str>>, prompt: P, state: S) -> Self
+    where
+        P: Prompt<State = S> + Clone + 'a,
+        S: State + ToAnswer + 'a,
+    {
+        Self {
+            name: name.into(),
+            field: Some(Box::new(PromptField { prompt, state })),
+            build: None,
+            when: None,
+            skipped: false,
+            prefilled: false,
+        }
+    }
+
+    /// Creates a question whose prompt and state are built from the answers to the previous
+    /// questions when the question is asked.
+    ///
+    /// ```
+    /// use tui_prompts::prelude::*;
+    ///
+    /// let question = Question::dynamic("branch", |answers: &Answers| {
+    ///     let repo = answers.get("repo").and_then(Answer::as_text).unwrap_or_default();
+    ///     let branches = match repo {
+    ///         "ratatui" => vec!["main", "v0.27"],
+    ///         _ => vec!["main"],
+    ///     };
+    ///     (
+    ///         AutocompletePrompt::from(format!("Branch of {repo}")),
+    ///         AutocompleteState::new(branches).with_value("main"),
+    ///     )
+    /// });
+    /// ```
+    #[must_use]
+    pub fn dynamic<P, S, F>(name: impl Into<Cow<'a, str>>, build: F) -> Self
+    where
+        F: FnOnce(&Answers) -> (P, S) + 'a,
+        P: Prompt<State = S> + Clone + 'a,
+        S: State + ToAnswer + 'a,
+    {
+        Self {
+            name: name.into(),
+            field: None,
+            build: Some(Box::new(
+                |answers: &Answers| -> Box<dyn QuestionField + 'a> {
+                    let (prompt, state) = build(answers);
+                    Box::new(PromptField { prompt, state })
+                },
+            )),
+            when: None,
+            skipped: false,
+            prefilled: false,
+        }
+    }
+
+    /// Sets a condition on the answers to the previous questions for the question to be asked.
+    ///
+    /// A question whose condition is false is skipped and has no answer.
+    #[must_use]
+    pub fn with_when(mut self, when: impl Fn(&Answers) -> bool + 'a) -> Self {
+        self.when = Some(Box::new(when));
+        self
+    }
+
+    /// The name of the question, which is the key of its answer.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The state of the prompt of the question.
+    ///
+    /// This is `None` for a [dynamic](Question::dynamic) question that has not been asked yet.
+    #[must_use]
+    pub fn state(&self) -> Option<&dyn State> {
+        self.field.as_ref().map(|field| field.state())
+    }
+
+    /// A mutable reference to the state of the prompt of the question.
+    pub fn state_mut(&mut self) -> Option<&mut dyn State> {
+        self.field.as_mut().map(|field| field.state_mut())
+    }
+
+    /// The typed answer of the prompt.
+    #[must_use]
+    pub fn answer(&self) -> Option<Answer> {
+        self.field.as_ref().map(|field| field.answer())
+    }
+
+    /// Whether the question was skipped because its condition was false.
+    #[must_use]
+    pub const fn is_skipped(&self) -> bool {
+        self.skipped
+    }
+
+    /// Whether the question was asked and is finished.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.state()
+            .is_some_and(|state| state.status().is_finished())
+    }
+
+    /// Evaluates the condition of the question and builds a dynamic question, returning whether
+    /// the question is asked.
+    fn prepare(&mut self, answers: &Answers) -> bool {
+        if self.when.as_ref().is_some_and(|when| !when(answers)) {
+            self.skipped = true;
+            return false;
+        }
+        self.skipped = false;
+        if let Some(build) = self.build.take() {
+            self.field = Some(build(answers));
+        }
+        true
+    }
+
+    /// Types the pre-filled value of the question into its state once, and submits it unless it is
+    /// reviewed, returning whether the question is done.
+    fn prefill(&mut self, prefill: &Prefill) -> bool {
+        if self.prefilled {
+            return false;
+        }
+        let Some(value) = prefill.get(&self.name) else {
+            return false;
+        };
+        let Some(field) = &mut self.field else {
+            return false;
+        };
+        self.prefilled = true;
+        let state = field.state_mut();
+        type_value(state, value);
+        if !prefill.is_review() && state.status().is_pending() {
+            state.handle_key_event(KeyCode::Enter.into());
+        }
+        state.status().is_done()
+    }
+
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        if let Some(field) = &mut self.field {
+            field.render(area, buf);
+        }
+    }
+}
+
+impl fmt::Debug for Question<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Question")
+            .field("name", &self.name)
+            .field("status", &self.state().map(State::status))
+            .field("skipped", &self.skipped)
+            .field("prefilled", &self.prefilled)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The state of a [`Questionnaire`]: named questions that are asked one after another.
+///
+/// Key events are routed to the current question. Once it is done, its answer is collected and the
+/// next question is asked, skipping questions whose condition is false. The questionnaire is
+/// [`Status::Done`] once every question is answered, and [`Status::Aborted`] as soon as any
+/// question is aborted.
+///
+/// Questions can be answered in advance by a [`Prefill`], see [`QuestionnaireState::with_prefill`].
+#[derive(Debug)]
+pub struct QuestionnaireState<'a> {
+    status: Status,
+    questions: Vec<Question<'a>>,
+    current: usize,
+    answers: Answers,
+    prefill: Prefill,
+}
+
+impl Default for QuestionnaireState<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> QuestionnaireState<'a> {
+    /// Creates an empty questionnaire, which is done until a question is added.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            status: Status::Done,
+            questions: Vec::new(),
+            current: 0,
+            answers: Answers::new(),
+            prefill: Prefill::new(),
+        }
+    }
+
+    /// Adds a question to the end of the questionnaire.
+    #[must_use]
+    pub fn with_question(mut self, question: Question<'a>) -> Self {
+        self.push(question);
+        self
+    }
+
+    /// Pre-fills the questions with the values of the same names as they are asked, which are
+    /// submitted without showing the questions unless the pre-fill is reviewed.
+    #[must_use]
+    pub fn with_prefill(mut self, prefill: Prefill) -> Self {
+        self.prefill = prefill;
+        self.ask();
+        self
+    }
+
+    /// Adds a question to the end of the questionnaire.
+    pub fn push(&mut self, question: Question<'a>) {
+        self.questions.push(question);
+        self.ask();
+    }
+
+    #[must_use]
+    pub const fn status(&self) -> Status {
+        self.status
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    #[must_use]
+    pub fn questions(&self) -> &[Question<'a>] {
+        &self.questions
+    }
+
+    /// The index of the question that is being asked, if the questionnaire is pending.
+    #[must_use]
+    pub fn current(&self) -> Option<usize> {
+        (self.status.is_pending() && self.current < self.questions.len()).then_some(self.current)
+    }
+
+    /// The question that is being asked, if the questionnaire is pending.
+    #[must_use]
+    pub fn current_question(&self) -> Option<&Question<'a>> {
+        self.current().map(|index| &self.questions[index])
+    }
+
+    /// A mutable reference to the question that is being asked, if the questionnaire is pending.
+    pub fn current_question_mut(&mut self) -> Option<&mut Question<'a>> {
+        self.current().map(|index| &mut self.questions[index])
+    }
+
+    /// The answers collected so far.
+    #[must_use]
+    pub const fn answers(&self) -> &Answers {
+        &self.answers
+    }
+
+    /// The answers to all questions, if the questionnaire is done.
+    #[must_use]
+    pub fn into_answers(self) -> Option<Answers> {
+        self.status.is_done().then_some(self.answers)
+    }
+
+    /// The questions that are finished and rendered as summary lines, followed by the current
+    /// question, if any. Skipped questions are not included.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn asked_mut(&mut self) -> impl Iterator<Item = &mut Question<'a>> {
+        let asked = (self.current + 1).min(self.questions.len());
+        self.questions[..asked]
+            .iter_mut()
+            .filter(|question| !question.is_skipped())
+    }
+
+    pub fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release || self.is_finished() {
+            return;
+        }
+        self.update_current(|state| state.handle_key_event(key_event));
+    }
+
+    /// Pastes text into the current question.
+    pub fn handle_paste(&mut self, text: &str) {
+        if !self.is_finished() {
+            self.update_current(|state| state.handle_paste(text));
+        }
+    }
+
+    /// Passes an event to the state of the current question and moves on once it is finished.
+    fn update_current(&mut self, handle: impl FnOnce(&mut dyn State)) {
+        let Some(question) = self.current_question_mut() else {
+            return;
+        };
+        let Some(state) = question.state_mut() else {
+            return;
+        };
+        handle(state);
+        match state.status() {
+            Status::Pending => {}
+            Status::Aborted => {
+                state.blur();
+                self.status = Status::Aborted;
+            }
+            Status::Done => {
+                self.answer_current();
+                self.ask();
+            }
+        }
+    }
+
+    /// Collects the answer of the current question, which is done, and moves to the next question.
+    fn answer_current(&mut self) {
+        let question = &mut self.questions[self.current];
+        if let Some(state) = question.state_mut() {
+            state.blur();
+        }
+        if let Some(answer) = question.answer() {
+            let name = question.name().to_string();
+            self.answers.insert(name, answer);
+        }
+        self.current += 1;
+    }
+
+    /// Focuses the current question, skipping questions whose condition is false and answering
+    /// pre-filled questions, or marks the questionnaire as done if all questions are answered.
+    fn ask(&mut self) {
+        while let Some(question) = self.questions.get_mut(self.current) {
+            if !question.prepare(&self.answers) {
+                self.current += 1;
+                continue;
+            }
+            if let Some(state) = question.state_mut() {
+                state.focus();
+            }
+            // a question that is added to a done questionnaire is asked
+            if self.status.is_done() {
+                self.status = Status::Pending;
+            }
+            if !question.prefill(&self.prefill) {
+                return;
+            }
+            self.answer_current();
+        }
+        if self.status.is_pending() {
+            self.status = Status::Done;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+    use time::macros::date;
+
+    use super::*;
+    use crate::test_utils::type_str;
+
+    fn questionnaire() -> QuestionnaireState<'static> {
+        QuestionnaireState::new()
+            .with_question(Question::new(
+                "name",
+                TextPrompt::from("Name"),
+                TextState::new(),
+            ))
+            .with_question(Question::new(
+                "birthday",
+                DatePrompt::from("Birthday"),
+                DateState::from(date!(2000 - 01 - 01)),
+            ))
+    }
+
+    #[test]
+    fn new() {
+        let state = questionnaire();
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.current(), Some(0));
+        assert!(state.questions()[0].state().unwrap().is_focused());
+        assert!(!state.questions()[1].state().unwrap().is_focused());
+        assert!(state.answers().is_empty());
+    }
+
+    #[test]
+    fn empty_is_done() {
+        let state = QuestionnaireState::new();
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.current(), None);
+        assert!(state.into_answers().unwrap().is_empty());
+
+        let state = QuestionnaireState::new().with_question(Question::new(
+            "name",
+            TextPrompt::from("Name"),
+            TextState::new(),
+        ));
+        assert_eq!(state.status(), Status::Pending);
+    }
+
+    #[test]
+    fn asks_questions_in_order() {
+        let mut state = questionnaire();
+        type_str(&mut state, "Ada");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.current(), Some(1));
+        assert_eq!(state.current_question().unwrap().name(), "birthday");
+        assert!(state.questions()[1].state().unwrap().is_focused());
+        assert_eq!(
+            state.answers().get("name").and_then(Answer::as_text),
+            Some("Ada")
+        );
+
+        state.handle_key_event(KeyCode::Right.into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.current(), None);
+        let answers = state.into_answers().unwrap();
+        assert_eq!(
+            answers.get("birthday").and_then(Answer::as_date),
+            Some(date!(2000 - 01 - 02))
+        );
+    }
+
+    #[test]
+    fn pastes_into_current_question() {
+        let mut state = questionnaire();
+        state.handle_paste("Ada");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            state.answers().get("name").and_then(Answer::as_text),
+            Some("Ada")
+        );
+    }
+
+    #[test]
+    fn abort_aborts_questionnaire() {
+        let mut state = questionnaire();
+        state.handle_key_event(KeyCode::Enter.into());
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+        assert_eq!(state.current(), None);
+        assert!(state.into_answers().is_none());
+    }
+
+    #[test]
+    fn when_skips_question() {
+        let mut state = QuestionnaireState::new()
+            .with_question(Question::new(
+                "vcs",
+                AutocompletePrompt::from("VCS"),
+                AutocompleteState::new(["git", "none"]),
+            ))
+            .with_question(
+                Question::new("remote", TextPrompt::from("Remote"), TextState::new()).with_when(
+                    |answers| answers.get("vcs").and_then(Answer::as_text) == Some("git"),
+                ),
+            )
+            .with_question(Question::new(
+                "license",
+                TextPrompt::from("License"),
+                TextState::new(),
+            ));
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.current_question().unwrap().name(), "license");
+        assert!(state.questions()[1].is_skipped());
+        assert!(!state.answers().contains("remote"));
+    }
+
+    #[test]
+    fn skipped_last_question_finishes() {
+        let mut state = QuestionnaireState::new()
+            .with_question(Question::new(
+                "name",
+                TextPrompt::from("Name"),
+                TextState::new(),
+            ))
+            .with_question(
+                Question::new("email", TextPrompt::from("Email"), TextState::new())
+                    .with_when(|_| false),
+            );
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.into_answers().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn dynamic_question() {
+        let mut state = QuestionnaireState::new()
+            .with_question(Question::new(
+                "repo",
+                TextPrompt::from("Repository"),
+                TextState::new(),
+            ))
+            .with_question(Question::dynamic("branch", |answers: &Answers| {
+                let repo = answers
+                    .get("repo")
+                    .and_then(Answer::as_text)
+                    .unwrap_or_default();
+                (
+                    AutocompletePrompt::from(format!("Branch of {repo}")),
+                    AutocompleteState::new([format!("{repo}-main")]),
+                )
+            }));
+        assert!(state.questions()[1].state().is_none());
+        type_str(&mut state, "tui");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            state.current_question().unwrap().state().unwrap().value(),
+            ""
+        );
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            state.answers().get("branch").and_then(Answer::as_text),
+            Some("tui-main")
+        );
+    }
+
+    fn not_empty(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            Err("Required".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn prefill_questionnaire() -> QuestionnaireState<'static> {
+        QuestionnaireState::new()
+            .with_question(Question::new(
+                "name",
+                TextPrompt::from("Name"),
+                TextState::new().with_validator(not_empty),
+            ))
+            .with_question(Question::new(
+                "git",
+                ConfirmPrompt::from("Git?"),
+                ConfirmState::new(),
+            ))
+            .with_question(Question::dynamic("branch", |_: &Answers| {
+                (
+                    AutocompletePrompt::from("Branch"),
+                    AutocompleteState::new(["main", "develop"]).with_value("main"),
+                )
+            }))
+    }
+
+    #[test]
+    fn prefill_skips_questions() {
+        let prefill = Prefill::new()
+            .with_value("name", "Ada")
+            .with_value("git", "yes")
+            .with_value("branch", "dev");
+        let state = prefill_questionnaire().with_prefill(prefill);
+        assert_eq!(state.status(), Status::Done);
+        let answers = state.into_answers().unwrap();
+        assert_eq!(answers.get("name").and_then(Answer::as_text), Some("Ada"));
+        assert_eq!(answers.get("git").and_then(Answer::as_bool), Some(true));
+        assert_eq!(
+            answers.get("branch").and_then(Answer::as_text),
+            Some("develop")
+        );
+    }
+
+    #[test]
+    fn prefill_asks_rejected_and_missing_values() {
+        let prefill = Prefill::new()
+            .with_value("name", "")
+            .with_value("git", "no");
+        let mut state = prefill_questionnaire().with_prefill(prefill);
+        assert_eq!(state.current(), Some(0));
+        assert_eq!(
+            state.questions()[0].state().unwrap().status(),
+            Status::Pending
+        );
+        type_str(&mut state, "Ada");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.current_question().unwrap().name(), "branch");
+        assert_eq!(
+            state.answers().get("git").and_then(Answer::as_bool),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn prefill_review() {
+        let prefill = Prefill::new().with_value("name", "Ada").with_review(true);
+        let mut state = prefill_questionnaire().with_prefill(prefill);
+        assert_eq!(state.current(), Some(0));
+        assert_eq!(state.questions()[0].state().unwrap().value(), "Ada");
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(
+            state.answers().get("name").and_then(Answer::as_text),
+            Some("Ada")
+        );
+        // adding a question does not fill in the value again
+        state.push(Question::new(
+            "email",
+            TextPrompt::from("Email"),
+            TextState::new(),
+        ));
+        assert_eq!(state.current(), Some(1));
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+use zeroize::Zeroize;
+
+/// A secret string, such as a password, that is zeroized when dropped.
+///
+/// The value is not shown by `Debug`. Use [`Secret::expose_secret`] to access it.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_prompts::prelude::*;
+///
+/// let mut state = TextState::new().with_secret(true).with_value("hunter2");
+/// let password = state.take_secret();
+/// assert_eq!(password.expose_secret(), "hunter2");
+/// assert_eq!(format!("{password:?}"), "Secret([REDACTED])");
+/// ```
+#[derive(Default)]
+pub struct Secret(String);
+
+impl Secret {
+    /// The secret value.
+    #[must_use]
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the secret value is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expose_secret() {
+        let secret = Secret::from("hunter2".to_string());
+        assert_eq!(secret.expose_secret(), "hunter2");
+        assert!(!secret.is_empty());
+        assert!(Secret::default().is_empty());
+    }
+
+    #[test]
+    fn debug() {
+        let secret = Secret::from("hunter2".to_string());
+        assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+    }
+}
@@ -0,0 +1,186 @@
+use std::ops::Range;
+
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    prelude::*,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{prelude::*, State};
+
+/// A part of a value that is edited on its own, e.g. the hours of a time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct Segment {
+    /// The text of the segment.
+    pub(crate) text: String,
+    /// The text between the segment and the next one.
+    pub(crate) suffix: &'static str,
+}
+
+impl Segment {
+    pub(crate) fn new(text: impl Into<String>, suffix: &'static str) -> Self {
+        Self {
+            text: text.into(),
+            suffix,
+        }
+    }
+}
+
+/// The text of the segments, e.g. `14:30`.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn join(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .flat_map(|segment| [segment.text.as_str(), segment.suffix])
+        .collect()
+}
+
+/// A value that is edited in segments.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) trait Segmented {
+    /// The segments of the value, in order.
+    fn segments(&self) -> Vec<Segment>;
+
+    /// Adds the delta to the segment, rolling over at the bounds of the segment.
+    fn step(&mut self, segment: usize, delta: i64);
+
+    /// Sets the value from typed text, or returns an error message if the text is invalid.
+    fn parse(&mut self, text: &str) -> Result<(), String>;
+}
+
+/// Edits a [`Segmented`] value, either segment by segment or by typing it.
+///
+/// While no text is typed, the input holds the formatted value with the cursor at the start of
+/// the focused segment. Typing a character replaces the value, and the typed text is parsed when
+/// the focus moves to another segment or the prompt is completed.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct SegmentEditor<'a> {
+    pub(crate) input: TextState<'a>,
+    segment: usize,
+    typing: bool,
+    error: Option<String>,
+}
+
+impl SegmentEditor<'_> {
+    pub(crate) fn new(value: &impl Segmented) -> Self {
+        let mut editor = Self::default();
+        editor.sync(value);
+        editor
+    }
+
+    pub(crate) const fn segment(&self) -> usize {
+        self.segment
+    }
+
+    pub(crate) const fn is_typing(&self) -> bool {
+        self.typing
+    }
+
+    pub(crate) fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The character range of the focused segment in the input, unless text is being typed.
+    pub(crate) fn segment_range(&self, value: &impl Segmented) -> Option<Range<usize>> {
+        if self.typing {
+            return None;
+        }
+        let segments = value.segments();
+        let start = segments[..self.segment]
+            .iter()
+            .map(|segment| segment.text.chars().count() + segment.suffix.chars().count())
+            .sum();
+        let len = segments[self.segment].text.chars().count();
+        Some(start..start + len)
+    }
+
+    /// Replaces the input with the formatted value and moves the cursor to the focused segment.
+    pub(crate) fn sync(&mut self, value: &impl Segmented) {
+        let segments = value.segments();
+        self.segment = self.segment.min(segments.len() - 1);
+        *self.input.value_mut() = join(&segments);
+        self.typing = false;
+        *self.input.position_mut() = self.segment_range(value).map_or(0, |range| range.start);
+    }
+
+    /// Parses the typed text, if any, and formats the value. Returns whether the text was valid.
+    pub(crate) fn commit(&mut self, value: &mut impl Segmented) -> bool {
+        if self.typing {
+            if let Err(error) = value.parse(self.input.value()) {
+                self.error = Some(error);
+                return false;
+            }
+        }
+        self.sync(value);
+        true
+    }
+
+    /// Handles a key event other than Enter, which completes the prompt.
+    pub(crate) fn handle_key_event(&mut self, key_event: KeyEvent, value: &mut impl Segmented) {
+        self.error = None;
+        let segments = value.segments().len();
+        match key_event.code {
+            KeyCode::Left if self.commit(value) => {
+                self.segment = self.segment.saturating_sub(1);
+                self.sync(value);
+            }
+            KeyCode::Right if self.commit(value) => {
+                self.segment = (self.segment + 1).min(segments - 1);
+                self.sync(value);
+            }
+            KeyCode::Up if self.commit(value) => {
+                value.step(self.segment, 1);
+                self.sync(value);
+            }
+            KeyCode::Down if self.commit(value) => {
+                value.step(self.segment, -1);
+                self.sync(value);
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down => {}
+            _ => {
+                if !self.typing {
+                    self.typing = true;
+                    if let (KeyCode::Char(_), KeyModifiers::NONE | KeyModifiers::SHIFT) =
+                        (key_event.code, key_event.modifiers)
+                    {
+                        self.input.truncate();
+                    } else {
+                        self.input.move_end();
+                    }
+                }
+                self.input.handle_key_event(key_event);
+            }
+        }
+    }
+}
+
+/// Renders the input of a segmented prompt, with the focused segment reversed, and the error on
+/// the line below it.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn render<'a>(
+    prompt: TextPrompt<'a>,
+    theme: &Theme,
+    area: Rect,
+    buf: &mut Buffer,
+    input: &mut TextState<'a>,
+    range: Option<Range<usize>>,
+    error: Option<&str>,
+) {
+    let [input_area, error_area] = Layout::vertical([Constraint::Length(1); 2]).areas(area);
+    prompt.render(input_area, buf, input);
+    if !input.status().is_pending() {
+        return;
+    }
+    if let Some(range) = range {
+        // the cursor is at the start of the focused segment
+        let (x, y) = input.cursor();
+        #[allow(clippy::cast_possible_truncation)]
+        let segment = Rect::new(x, y, range.len() as u16, 1).intersection(input_area);
+        buf.set_style(segment, theme.value.reversed());
+    }
+    if let Some(error) = error.filter(|_| !error_area.is_empty()) {
+        Span::styled(error.to_string(), theme.error).render(error_area, buf);
+    }
+}
@@ -1,4 +1,9 @@
-use ratatui::{style::Stylize, text::Span};
+use std::borrow::Cow;
+
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
 
 /// The result of a prompt.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -30,35 +35,85 @@ impl Status {
         matches!(self, Self::Done | Self::Aborted)
     }
 
+    /// The default symbol for the status.
+    ///
+    /// Use [`Symbols::symbol`] to get the symbol from a custom set of symbols.
     #[must_use]
     pub fn symbol(&self) -> Span<'static> {
-        match self {
-            Self::Pending => Symbols::default().pending,
-            Self::Aborted => Symbols::default().aborted,
-            Self::Done => Symbols::default().done,
-        }
+        Symbols::default().symbol(*self)
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Symbols {
+/// The symbols that are displayed before a prompt to indicate its [`Status`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symbols {
     pub pending: Span<'static>,
     pub aborted: Span<'static>,
     pub done: Span<'static>,
 }
 
-impl Default for Symbols {
-    fn default() -> Self {
+impl Symbols {
+    /// The default symbols: a cyan `?`, a red `✘` and a green `✔`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            pending: Span {
+                content: Cow::Borrowed("?"),
+                style: Style::new().fg(Color::Cyan),
+            },
+            aborted: Span {
+                content: Cow::Borrowed("✘"),
+                style: Style::new().fg(Color::Red),
+            },
+            done: Span {
+                content: Cow::Borrowed("✔"),
+                style: Style::new().fg(Color::Green),
+            },
+        }
+    }
+
+    /// ASCII-only symbols: a cyan `?`, a red `x` and a green `v`.
+    ///
+    /// These are useful on terminals and in logs that cannot display the default symbols.
+    #[must_use]
+    pub const fn ascii() -> Self {
         Self {
-            pending: "?".cyan(),
-            aborted: "✘".red(),
-            done: "✔".green(),
+            pending: Span {
+                content: Cow::Borrowed("?"),
+                style: Style::new().fg(Color::Cyan),
+            },
+            aborted: Span {
+                content: Cow::Borrowed("x"),
+                style: Style::new().fg(Color::Red),
+            },
+            done: Span {
+                content: Cow::Borrowed("v"),
+                style: Style::new().fg(Color::Green),
+            },
+        }
+    }
+
+    /// The symbol for the given status.
+    #[must_use]
+    pub fn symbol(&self, status: Status) -> Span<'static> {
+        match status {
+            Status::Pending => self.pending.clone(),
+            Status::Aborted => self.aborted.clone(),
+            Status::Done => self.done.clone(),
         }
     }
 }
 
+impl Default for Symbols {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use ratatui::style::Stylize;
+
     use super::*;
 
     #[test]
@@ -113,6 +168,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn symbols_ascii() {
+        assert_eq!(
+            Symbols::ascii(),
+            Symbols {
+                pending: "?".cyan(),
+                aborted: "x".red(),
+                done: "v".green(),
+            }
+        );
+    }
+
     #[test]
     fn symbols_custom() {
         let symbols = Symbols {
@@ -124,4 +191,16 @@ mod tests {
         assert_eq!(symbols.aborted, "A".red());
         assert_eq!(symbols.done, "D".green());
     }
+
+    #[test]
+    fn symbols_symbol() {
+        let symbols = Symbols {
+            pending: "P".cyan(),
+            aborted: "A".red(),
+            done: "D".green(),
+        };
+        assert_eq!(symbols.symbol(Status::Pending), "P".cyan());
+        assert_eq!(symbols.symbol(Status::Aborted), "A".red());
+        assert_eq!(symbols.symbol(Status::Done), "D".green());
+    }
 }
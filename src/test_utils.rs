@@ -0,0 +1,61 @@
+//! Helpers shared by the tests of the prompts.
+
+use std::fs;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use tempfile::TempDir;
+
+use crate::{FormState, QuestionnaireState, State};
+
+/// A prompt state, or a state of several prompts, that keys are typed into.
+pub trait TypeKeys {
+    fn type_key(&mut self, key_event: KeyEvent);
+}
+
+impl<S: State + ?Sized> TypeKeys for S {
+    fn type_key(&mut self, key_event: KeyEvent) {
+        self.handle_key_event(key_event);
+    }
+}
+
+impl TypeKeys for FormState<'_> {
+    fn type_key(&mut self, key_event: KeyEvent) {
+        self.handle_key_event(key_event);
+    }
+}
+
+impl TypeKeys for QuestionnaireState<'_> {
+    fn type_key(&mut self, key_event: KeyEvent) {
+        self.handle_key_event(key_event);
+    }
+}
+
+/// Types the characters of the value one key at a time.
+pub fn type_str(state: &mut impl TypeKeys, value: &str) {
+    for c in value.chars() {
+        state.type_key(KeyCode::Char(c).into());
+    }
+}
+
+/// A temporary directory containing:
+///
+/// ```text
+/// .git/
+/// .hidden
+/// Cargo.toml
+/// README.md
+/// src/
+///   lib.rs
+///   main.rs
+/// ```
+pub fn temp_dir() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join(".git")).unwrap();
+    fs::write(dir.path().join(".hidden"), "").unwrap();
+    fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+    fs::write(dir.path().join("README.md"), "").unwrap();
+    fs::create_dir(dir.path().join("src")).unwrap();
+    fs::write(dir.path().join("src/lib.rs"), "").unwrap();
+    fs::write(dir.path().join("src/main.rs"), "").unwrap();
+    dir
+}
@@ -1,6 +1,6 @@
-use std::{borrow::Cow, vec};
+use std::{borrow::Cow, time::Duration, vec};
 
-use crate::prelude::*;
+use crate::{prelude::*, PasswordStrength};
 
 use itertools::Itertools;
 use ratatui::{
@@ -8,21 +8,24 @@ use ratatui::{
     widgets::{Block, Paragraph, StatefulWidget, Widget},
 };
 
-// TODO style the widget
-// TODO style each element of the widget.
 // TODO handle multi-line input.
 // TODO handle scrolling.
 // TODO handle vertical movement.
-// TODO handle bracketed paste.
 
 /// A prompt widget that displays a message and a text input.
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TextPrompt<'a> {
     /// The message to display to the user before the input.
     message: Cow<'a, str>,
     /// The block to wrap the prompt in.
     block: Option<Block<'a>>,
     render_style: TextRenderStyle,
+    /// The character displayed in place of each character of a password.
+    password_char: char,
+    /// How long the last typed character of a password is shown before it is masked.
+    reveal_last_char: Option<Duration>,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -33,17 +36,6 @@ pub enum TextRenderStyle {
     Invisible,
 }
 
-impl TextRenderStyle {
-    #[must_use]
-    pub fn render(&self, state: &TextState) -> String {
-        match self {
-            Self::Default => state.value().to_string(),
-            Self::Password => "*".repeat(state.len()),
-            Self::Invisible => String::new(),
-        }
-    }
-}
-
 impl<'a> TextPrompt<'a> {
     #[must_use]
     pub const fn new(message: Cow<'a, str>) -> Self {
@@ -51,6 +43,9 @@ impl<'a> TextPrompt<'a> {
             message,
             block: None,
             render_style: TextRenderStyle::Default,
+            password_char: '*',
+            reveal_last_char: None,
+            theme: Theme::new(),
         }
     }
 
@@ -67,6 +62,32 @@ impl<'a> TextPrompt<'a> {
         self.render_style = render_style;
         self
     }
+
+    /// Sets the character displayed in place of each character of a password.
+    ///
+    /// Defaults to `*`.
+    #[must_use]
+    pub const fn with_password_char(mut self, password_char: char) -> Self {
+        self.password_char = password_char;
+        self
+    }
+
+    /// Shows the last typed character of a password for the given duration before masking it.
+    ///
+    /// The application needs to redraw the prompt after the duration to mask the character.
+    #[must_use]
+    pub const fn with_reveal_last_char(mut self, duration: Duration) -> Self {
+        self.reveal_last_char = Some(duration);
+        self
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
 }
 
 impl Prompt for TextPrompt<'_> {
@@ -87,24 +108,30 @@ impl<'a> StatefulWidget for TextPrompt<'a> {
 
     fn render(mut self, mut area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         self.render_block(&mut area, buf);
+        state.set_password(self.render_style == TextRenderStyle::Password);
 
         let width = area.width as usize;
         let height = area.height as usize;
-        let value = self.render_style.render(state);
-        let value_length = value.chars().count();
 
-        let line = Line::from(vec![
-            state.status().symbol(),
+        let theme = &self.theme;
+        let mut spans = vec![
+            theme.symbols.symbol(state.status()),
             " ".into(),
-            self.message.bold(),
-            " › ".cyan().dim(),
-            Span::raw(value),
-        ]);
-        let prompt_length = line.width() - value_length;
-        let lines = wrap(line, width).take(height).collect_vec();
+            Span::styled(self.message.as_ref(), theme.message),
+        ];
+        if let Some(hint) = self.default_hint(state) {
+            spans.push(hint);
+        }
+        spans.push(theme.separator.clone());
+        let prompt_length: usize = spans.iter().map(Span::width).sum();
 
+        // the cursor skips over the literals of the mask
+        let position = match (state.mask(), self.render_style) {
+            (Some(mask), TextRenderStyle::Default) => mask.column(state.position()),
+            _ => state.position(),
+        };
         // constrain the position to the area
-        let position = (state.position() + prompt_length).min(area.area() as usize - 1);
+        let position = (position + prompt_length).min(area.area() as usize - 1);
         let row = position / width;
         let column = position % width;
         // sizes are already constrained to the u16 range
@@ -112,6 +139,35 @@ impl<'a> StatefulWidget for TextPrompt<'a> {
         {
             *state.cursor_mut() = (area.x + column as u16, area.y + row as u16);
         }
+
+        // the value is borrowed rather than copied, so that secret values are not left in memory
+        let value = self.render_value(state);
+        // rejected input is flagged by rendering the value in the error style
+        let style = if state.is_rejected() {
+            theme.value.patch(theme.error)
+        } else {
+            theme.value
+        };
+        if state.is_selected() {
+            spans.push(Span::styled(value, style.reversed()));
+        } else {
+            spans.push(Span::styled(value, style));
+        }
+        if let Some(placeholder) = self.mask_placeholder(state) {
+            spans.push(placeholder);
+        }
+        let mut lines = wrap(Line::from(spans), width).collect_vec();
+        // the suggestion is added after wrapping so that it never moves the input or the cursor,
+        // and is cut off at the end of the line
+        if let Some((suggestion, last)) = self.suggestion(state).zip(lines.last_mut()) {
+            last.spans.push(suggestion);
+        }
+        lines.extend(self.strength_meter(state));
+        if let Some(error) = state.error() {
+            lines.push(Line::styled(error, theme.error));
+        }
+        lines.extend(self.completions(state));
+        lines.truncate(height);
         Paragraph::new(lines).render(area, buf);
     }
 }
@@ -182,13 +238,121 @@ fn span_split_at(span: Span, mid: usize) -> (Span, Span) {
     (first, second)
 }
 
-impl<'a> TextPrompt<'a> {
+impl TextPrompt<'_> {
     fn render_block(&mut self, area: &mut Rect, buf: &mut Buffer) {
         if let Some(block) = self.block.take() {
             let inner = block.inner(*area);
             block.render(*area, buf);
             *area = inner;
-        };
+        }
+    }
+
+    /// The value as displayed by the render style.
+    fn render_value<'s>(&self, state: &'s TextState) -> Cow<'s, str> {
+        match self.render_style {
+            TextRenderStyle::Default => return state.formatted_value(),
+            // a finished prompt is left on screen, so the password is only shown while pending
+            TextRenderStyle::Password if state.is_revealed() && state.status().is_pending() => {
+                return Cow::Borrowed(state.value())
+            }
+            TextRenderStyle::Password => {}
+            TextRenderStyle::Invisible => return Cow::Borrowed(""),
+        }
+        let revealed = self
+            .reveal_last_char
+            .zip(state.last_typed())
+            .filter(|(duration, (_, typed_at))| typed_at.elapsed() < *duration)
+            .map(|(_, (index, _))| index);
+        state
+            .value()
+            .chars()
+            .enumerate()
+            .map(|(index, c)| {
+                if Some(index) == revealed {
+                    c
+                } else {
+                    self.password_char
+                }
+            })
+            .collect::<String>()
+            .into()
+    }
+
+    /// The unfilled part of the mask, rendered after the value of a pending prompt, e.g. `-____`
+    /// after `(555) 123`.
+    fn mask_placeholder(&self, state: &TextState) -> Option<Span<'static>> {
+        if !state.status().is_pending() || self.render_style != TextRenderStyle::Default {
+            return None;
+        }
+        let mask = state.mask()?;
+        let filled = state.formatted_value().chars().count();
+        let placeholder: String = mask.placeholder().chars().skip(filled).collect();
+        Some(Span::styled(placeholder, self.theme.placeholder))
+    }
+
+    /// The password strength meter, shown below a pending, non-empty input when the state has a
+    /// strength estimator.
+    fn strength_meter(&self, state: &TextState) -> Option<Line<'static>> {
+        if !state.status().is_pending() || state.is_empty() {
+            return None;
+        }
+        let strength = state.password_strength()?;
+        let [filled, empty] = self.theme.meter;
+        let style = self.theme.strength[strength.score()];
+        let segments = strength.score() + 1;
+        let total = PasswordStrength::ALL.len();
+        Some(Line::from(vec![
+            Span::styled(filled.repeat(segments), style),
+            Span::styled(empty.repeat(total - segments), style),
+            Span::styled(format!(" {}", strength.label()), style),
+        ]))
+    }
+
+    /// The candidates of the last completion, listed below the input of a pending prompt.
+    fn completions<'s>(&self, state: &'s TextState) -> impl Iterator<Item = Line<'s>> + 's {
+        let pending = state.status().is_pending();
+        let selected = state.completion_index();
+        let (hint, value) = (self.theme.hint, self.theme.value.reversed());
+        state
+            .completions()
+            .iter()
+            .enumerate()
+            .filter(move |_| pending)
+            .map(move |(index, candidate)| {
+                let style = if Some(index) == selected { value } else { hint };
+                Line::from(vec!["  ".into(), Span::styled(candidate.as_str(), style)])
+            })
+    }
+
+    /// The continuation of the value suggested by the history, shown after the cursor while the
+    /// prompt is pending.
+    fn suggestion<'s>(&self, state: &'s TextState) -> Option<Span<'s>> {
+        if !state.status().is_pending() || self.render_style != TextRenderStyle::Default {
+            return None;
+        }
+        Some(Span::styled(state.suggestion()?, self.theme.placeholder))
+    }
+
+    /// The hint showing the default value while the prompt is pending.
+    ///
+    /// The hint is not shown for password and invisible prompts, as it would reveal the default.
+    fn default_hint(&self, state: &TextState) -> Option<Span<'static>> {
+        if !state.status().is_pending() || state.is_selected() {
+            return None;
+        }
+        if self.render_style != TextRenderStyle::Default {
+            return None;
+        }
+        let default = state
+            .default_value()
+            .filter(|default| !default.is_empty())?;
+        Some(Span::styled(format!(" ({default})"), self.theme.hint))
+    }
+}
+
+impl Default for TextPrompt<'_> {
+    fn default() -> Self {
+        Self::new(Cow::Borrowed(""))
     }
 }
 
@@ -203,12 +367,12 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::Status;
+    use crate::{PasswordStrength, Status, Symbols};
     use ratatui_macros::line;
     use rstest::{fixture, rstest};
 
     use super::*;
-    use ratatui::{backend::TestBackend, widgets::Borders};
+    use ratatui::{backend::TestBackend, crossterm::event::KeyCode, widgets::Borders};
 
     #[test]
     fn new() {
@@ -216,6 +380,7 @@ mod tests {
         assert_eq!(PROMPT.message, "Enter your name");
         assert_eq!(PROMPT.block, None);
         assert_eq!(PROMPT.render_style, TextRenderStyle::Default);
+        assert_eq!(PROMPT.theme, Theme::default());
     }
 
     #[test]
@@ -224,6 +389,7 @@ mod tests {
         assert_eq!(prompt.message, "");
         assert_eq!(prompt.block, None);
         assert_eq!(prompt.render_style, TextRenderStyle::Default);
+        assert_eq!(prompt.theme, Theme::default());
     }
 
     #[test]
@@ -344,6 +510,262 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([line]));
     }
 
+    #[test]
+    fn render_password_char() {
+        let prompt = TextPrompt::from("prompt")
+            .with_render_style(TextRenderStyle::Password)
+            .with_password_char('•');
+        let mut state = TextState::new().with_value("value");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "?".cyan(),
+            " ",
+            "prompt".bold(),
+            " › ".cyan().dim(),
+            "•••••",
+            "    "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+    }
+
+    #[test]
+    fn render_password_reveal_last_char() {
+        let prompt = TextPrompt::from("prompt")
+            .with_render_style(TextRenderStyle::Password)
+            .with_reveal_last_char(Duration::from_secs(60));
+        let mut state = TextState::new().with_value("valu");
+        state.move_end();
+        state.handle_key_event(KeyCode::Char('e').into());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.clone().render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "?".cyan(),
+            " ",
+            "prompt".bold(),
+            " › ".cyan().dim(),
+            "****e",
+            "    "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+
+        // any other key masks the character again
+        state.handle_key_event(KeyCode::Left.into());
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "?".cyan(),
+            " ",
+            "prompt".bold(),
+            " › ".cyan().dim(),
+            "*****",
+            "    "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+    }
+
+    #[test]
+    fn render_password_revealed() {
+        let prompt = TextPrompt::from("prompt").with_render_style(TextRenderStyle::Password);
+        let mut state = TextState::new().with_value("value");
+        state.toggle_reveal();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "?".cyan(),
+            " ",
+            "prompt".bold(),
+            " › ".cyan().dim(),
+            "value",
+            "    "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+        assert_eq!(state.value(), "value");
+    }
+
+    #[test]
+    fn render_password_is_secret() {
+        let prompt = TextPrompt::from("prompt").with_render_style(TextRenderStyle::Password);
+        let mut state = TextState::new();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert!(state.is_secret());
+    }
+
+    #[test]
+    fn render_password_revealed_done() {
+        let prompt = TextPrompt::from("prompt").with_render_style(TextRenderStyle::Password);
+        let mut state = TextState::new().with_value("value");
+        state.toggle_reveal();
+        state.complete();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "✔".green(),
+            " ",
+            "prompt".bold(),
+            " › ".cyan().dim(),
+            "*****",
+            "    "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+    }
+
+    #[test]
+    fn render_password_strength() {
+        let prompt = TextPrompt::from("prompt").with_render_style(TextRenderStyle::Password);
+        let mut state = TextState::new()
+            .with_password_strength(PasswordStrength::estimate)
+            .with_value("hunter2");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let expected = Buffer::with_lines([
+            line![
+                "?".cyan(),
+                " ",
+                "prompt".bold(),
+                " › ".cyan().dim(),
+                "*******",
+                "  "
+            ],
+            line!["■■□□□ weak".light_red(), "          "],
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_password_strength_ascii() {
+        let prompt = TextPrompt::from("prompt")
+            .with_render_style(TextRenderStyle::Password)
+            .with_theme(Theme::ascii().no_color());
+        let mut state = TextState::new()
+            .with_min_strength(PasswordStrength::Strong)
+            .with_value("correct horse battery staple");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 3));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "? prompt > *********",
+                "*******************",
+                "##### very strong   ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_password_strength_no_room() {
+        let prompt = TextPrompt::from("prompt")
+            .with_render_style(TextRenderStyle::Password)
+            .with_theme(Theme::default().no_color());
+        let mut state = TextState::new()
+            .with_password_strength(PasswordStrength::estimate)
+            .with_value("hunter2");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer, Buffer::with_lines(["? prompt › *******  "]));
+    }
+
+    #[test]
+    fn render_completions() {
+        let completer = |_: &str, _: usize| vec!["black".to_string(), "blue".to_string()];
+        let prompt = TextPrompt::from("color");
+        let mut state = TextState::new().with_completer(completer).with_value("b");
+        state.move_end();
+        state.next_completion();
+        state.next_completion();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 4));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let expected = Buffer::with_lines([
+            line![
+                "?".cyan(),
+                " ",
+                "color".bold(),
+                " › ".cyan().dim(),
+                "black",
+                " "
+            ],
+            line!["  ", "black".reversed(), "         "],
+            line!["  ", "blue".dim(), "          "],
+            line![""],
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.cursor(), (15, 0));
+    }
+
+    #[test]
+    fn render_suggestion() {
+        let prompt = TextPrompt::from("cmd");
+        let mut state = TextState::new()
+            .with_history(["cargo test"])
+            .with_value("car");
+        state.move_end();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "?".cyan(),
+            " ",
+            "cmd".bold(),
+            " › ".cyan().dim(),
+            "car",
+            "go test".dim(),
+            "  "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+        assert_eq!(state.cursor(), (11, 0));
+        assert_eq!(state.value(), "car");
+    }
+
+    #[test]
+    fn render_suggestion_does_not_wrap() {
+        let prompt = TextPrompt::from("cmd").with_theme(Theme::default().no_color());
+        let mut state = TextState::new()
+            .with_history(["cargo test"])
+            .with_value("ca");
+        state.move_end();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer, Buffer::with_lines(["? cmd › carg", "            "]));
+        assert_eq!(state.cursor(), (10, 0));
+    }
+
+    #[test]
+    fn render_suggestion_done() {
+        let prompt = TextPrompt::from("cmd").with_theme(Theme::default().no_color());
+        let mut state = TextState::new()
+            .with_history(["cargo test"])
+            .with_value("car")
+            .with_status(Status::Done);
+        state.move_end();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer, Buffer::with_lines(["✔ cmd › car         "]));
+    }
+
     #[test]
     fn render_invisible() {
         let prompt = TextPrompt::from("prompt").with_render_style(TextRenderStyle::Invisible);
@@ -362,6 +784,141 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([line]));
     }
 
+    #[test]
+    fn render_with_default() {
+        let prompt = TextPrompt::from("port");
+        let mut state = TextState::new().with_default("8080");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "?".cyan(),
+            " ",
+            "port".bold(),
+            " (8080)".dim(),
+            " › ".cyan().dim(),
+            "    "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+        assert_eq!(state.cursor(), (16, 0));
+    }
+
+    #[test]
+    fn render_with_default_done() {
+        let prompt = TextPrompt::from("port");
+        let mut state = TextState::new().with_default("8080");
+        state.complete();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "✔".green(),
+            " ",
+            "port".bold(),
+            " › ".cyan().dim(),
+            "8080",
+            "  "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+    }
+
+    #[test]
+    fn render_with_default_selected() {
+        let prompt = TextPrompt::from("port");
+        let mut state = TextState::new().with_default_selected("8080");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "?".cyan(),
+            " ",
+            "port".bold(),
+            " › ".cyan().dim(),
+            "8080".reversed(),
+            "  "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+        assert_eq!(state.cursor(), (13, 0));
+    }
+
+    #[test]
+    fn render_with_theme() {
+        let theme = Theme {
+            symbols: Symbols {
+                pending: ">".magenta(),
+                ..Symbols::default()
+            },
+            message: Style::new().italic(),
+            separator: Span::raw(": "),
+            value: Style::new().yellow(),
+            ..Theme::default()
+        };
+        let prompt = TextPrompt::from("prompt").with_theme(theme);
+        let mut state = TextState::new().with_value("value");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            ">".magenta(),
+            " ",
+            "prompt".italic(),
+            ": ",
+            "value".yellow(),
+            "     "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+        assert_eq!(state.cursor(), (10, 0));
+    }
+
+    #[test]
+    fn render_with_ascii_theme() {
+        let prompt = TextPrompt::from("prompt").with_theme(Theme::ascii());
+        let mut state = TextState::new()
+            .with_value("value")
+            .with_status(Status::Done);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        let line = line![
+            "v".green(),
+            " ",
+            "prompt".bold(),
+            " > ".cyan().dim(),
+            "value",
+            "    "
+        ];
+        assert_eq!(buffer, Buffer::with_lines([line]));
+    }
+
+    #[test]
+    fn render_with_no_color_theme() {
+        let prompt = TextPrompt::from("prompt").with_theme(Theme::default().no_color());
+        let mut state = TextState::new().with_value("value").with_default("x");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer, Buffer::with_lines(["? prompt (x) › value"]));
+    }
+
+    #[test]
+    fn render_with_ascii_no_color_theme() {
+        let prompt = TextPrompt::from("prompt").with_theme(Theme::ascii().no_color());
+        let mut state = TextState::new()
+            .with_value("value")
+            .with_status(Status::Aborted);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer, Buffer::with_lines(["x prompt > value    "]));
+    }
+
     #[fixture]
     fn terminal() -> Terminal<TestBackend> {
         Terminal::new(TestBackend::new(17, 2)).unwrap()
@@ -455,4 +1012,70 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn render_rejected() {
+        let theme = Theme::default();
+        let prompt = TextPrompt::from("PIN").with_theme(theme.clone());
+        let mut state = TextState::new().with_char_filter(|c| c.is_ascii_digit());
+        state.handle_key_event(KeyCode::Char('1').into());
+        state.handle_key_event(KeyCode::Char('x').into());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+
+        prompt.clone().render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(8, 0).symbol(), "1");
+        assert_eq!(
+            buffer.get(8, 0).fg,
+            theme.value.patch(theme.error).fg.unwrap()
+        );
+
+        state.handle_key_event(KeyCode::Char('2').into());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer.get(8, 0).fg, theme.value.fg.unwrap_or_default());
+    }
+
+    #[test]
+    fn render_error() {
+        let prompt = TextPrompt::from("Name").with_theme(Theme::default().no_color());
+        let mut state = TextState::new().with_validator(|_| Err("Required".into()));
+        state.complete();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        prompt.render(buffer.area, &mut buffer, &mut state);
+        buffer.set_style(buffer.area, Style::reset());
+        assert_eq!(buffer, Buffer::with_lines(["? Name ›    ", "Required    "]));
+    }
+
+    #[test]
+    fn render_mask() {
+        let prompt = TextPrompt::from("Phone").with_theme(Theme::default().no_color());
+        let mut state = TextState::new().with_mask("(###) ###-####");
+        for c in "5551".chars() {
+            state.push(c);
+        }
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 24, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer, Buffer::with_lines(["? Phone › (555) 1__-____"]));
+        // the cursor is after the typed digit, past the literals
+        assert_eq!(state.cursor(), (17, 0));
+    }
+
+    #[test]
+    fn render_mask_done() {
+        let prompt = TextPrompt::from("Phone").with_theme(Theme::default().no_color());
+        let mut state = TextState::new()
+            .with_mask("(###) ###-####")
+            .with_masked_value(MaskedValue::Raw)
+            .with_value("5551234567")
+            .with_status(Status::Done);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 24, 1));
+
+        prompt.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer, Buffer::with_lines(["✔ Phone › (555) 123-4567"]));
+    }
 }
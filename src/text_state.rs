@@ -1,14 +1,115 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+    time::Instant,
+};
 
-use crate::{prelude::*, State};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use unicode_segmentation::UnicodeSegmentation;
+use zeroize::Zeroize;
 
+use crate::{
+    completion::common_prefix, prelude::*, prompt::handle_key_event, Completer, Mask,
+    PasswordStrength, Secret, State,
+};
+
+/// The state of a [`TextPrompt`].
+///
+/// Keybindings in addition to those of [`State`]:
+/// - Ctrl+R | Alt+V: Show / hide the value of a password prompt
+/// - Tab: Complete the common prefix of the candidates, then cycle through them (requires a
+///   [`Completer`], see [`TextState::with_completer`])
+/// - Shift+Tab: Cycle backwards through the candidates
+/// - Right | End | Ctrl+F | Ctrl+E: Accept the suggestion from the history at the end of the
+///   input (see [`TextState::with_history`])
+/// - Alt+F: Accept the next word of the suggestion
+///
+/// With a [`Mask`], only characters that fit the slots of the mask can be typed, and the cursor
+/// moves over the slots, skipping the literals of the mask. See [`TextState::with_mask`].
+///
+/// Typed and pasted characters can be constrained with [`TextState::with_char_filter`],
+/// [`TextState::with_max_length`] and [`TextState::with_case`]. Rejected input is flagged until
+/// the next key press, see [`TextState::is_rejected`].
+///
+/// A validator set with [`TextState::with_validator`] is checked when the prompt is completed. An
+/// invalid value keeps the prompt pending and sets the error until the next key press.
+///
+/// Passwords are secret, so that the value is zeroized when it is edited or dropped, and hidden
+/// from `Debug` and `Hash`. See [`TextState::with_secret`] for the guarantees.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct TextState<'a> {
     status: Status,
     focus: FocusState,
     position: usize,
     cursor: (u16, u16),
-    value: Cow<'a, str>,
+    value: TextValue<'a>,
+    default: Option<Cow<'a, str>>,
+    selected: bool,
+    reveal: Reveal,
+    last_typed: Option<(usize, Instant)>,
+    strength_estimator: Option<FnRef<StrengthEstimator>>,
+    min_strength: Option<PasswordStrength>,
+    completer: Option<CompleterRef>,
+    completions: Vec<String>,
+    completion_index: Option<usize>,
+    history: Vec<Cow<'a, str>>,
+    mask: Option<Mask>,
+    masked_value: MaskedValue,
+    char_filter: Option<FnRef<CharFilter>>,
+    max_length: Option<usize>,
+    case: Option<TextCase>,
+    rejected: bool,
+    validator: Option<FnRef<Validator>>,
+    error: Option<String>,
+}
+
+/// Estimates the strength of a password, see [`TextState::with_password_strength`].
+type StrengthEstimator = fn(&str) -> PasswordStrength;
+
+/// Accepts or rejects a typed character, see [`TextState::with_char_filter`].
+type CharFilter = fn(char) -> bool;
+
+/// Whether the value of a password prompt is shown, see [`TextState::toggle_reveal`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+enum Reveal {
+    /// The state is not rendered as a password, so the reveal toggle is not handled.
+    #[default]
+    Disabled,
+    Hidden,
+    Shown,
+}
+
+/// Checks a value when a prompt is completed, returning the error to display if it is invalid.
+pub type Validator = fn(&str) -> Result<(), String>;
+
+/// The case that typed and pasted characters are converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextCase {
+    Upper,
+    Lower,
+}
+
+impl TextCase {
+    /// Converts the character, which can result in more than one character, e.g. `ß` to `SS`.
+    #[must_use]
+    pub fn convert(self, c: char) -> Vec<char> {
+        match self {
+            Self::Upper => c.to_uppercase().collect(),
+            Self::Lower => c.to_lowercase().collect(),
+        }
+    }
+}
+
+/// The text that [`State::value`] returns for a [`TextState`] with a [`Mask`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaskedValue {
+    /// The value with the literals of the mask, e.g. `(555) 123-4567`.
+    #[default]
+    Formatted,
+    /// Only the typed characters, e.g. `5551234567`.
+    Raw,
 }
 
 impl<'a> TextState<'a> {
@@ -19,7 +120,25 @@ impl<'a> TextState<'a> {
             focus: FocusState::Unfocused,
             position: 0,
             cursor: (0, 0),
-            value: Cow::Borrowed(""),
+            value: TextValue::new(),
+            default: None,
+            selected: false,
+            reveal: Reveal::Disabled,
+            last_typed: None,
+            strength_estimator: None,
+            min_strength: None,
+            completer: None,
+            completions: Vec::new(),
+            completion_index: None,
+            history: Vec::new(),
+            mask: None,
+            masked_value: MaskedValue::Formatted,
+            char_filter: None,
+            max_length: None,
+            case: None,
+            rejected: false,
+            validator: None,
+            error: None,
         }
     }
 
@@ -37,10 +156,541 @@ impl<'a> TextState<'a> {
 
     #[must_use]
     pub fn with_value(mut self, value: impl Into<Cow<'a, str>>) -> Self {
-        self.value = value.into();
+        self.value.set(value.into());
+        self.apply_mask();
+        self
+    }
+
+    /// Marks the value as secret, e.g. for a password.
+    ///
+    /// A [`TextPrompt`] with [`TextRenderStyle::Password`] marks the state as secret when it is
+    /// rendered, but a state that holds a password before it is first rendered should be marked
+    /// with this method. Use [`TextState::take_secret`] to get the value as a [`Secret`].
+    ///
+    /// A secret value is hidden from `Debug` and `Hash`, and its bytes are zeroized when the value
+    /// is edited, when it outgrows its buffer and when it is dropped. An owned default value is
+    /// zeroized when the state is dropped, and each clone of the state zeroizes its own copy.
+    ///
+    /// The value is not zeroized where it is borrowed rather than owned, e.g. a `&'static str`
+    /// passed to [`TextState::with_value`], where it is copied out of the state, e.g. by
+    /// [`State::value`] or a revealed password rendered into a buffer, or when it is edited
+    /// through [`State::value_mut`].
+    #[must_use]
+    pub const fn with_secret(mut self, secret: bool) -> Self {
+        self.value.secret = secret;
+        self
+    }
+
+    /// Whether the value is secret.
+    #[must_use]
+    pub const fn is_secret(&self) -> bool {
+        self.value.secret
+    }
+
+    /// Takes the value out of the state as a [`Secret`], leaving the value empty.
+    pub fn take_secret(&mut self) -> Secret {
+        self.position = 0;
+        self.value.take().into()
+    }
+
+    /// Sets the default value of the prompt.
+    ///
+    /// The default is shown as a hint and becomes the value when the prompt is completed with an
+    /// empty input.
+    #[must_use]
+    pub fn with_default(mut self, default: impl Into<Cow<'a, str>>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Sets the default value of the prompt and pre-fills the input with it.
+    ///
+    /// The pre-filled value is selected, so the first typed character replaces it and the first
+    /// deletion clears it. Any other key deselects it and keeps the value for editing.
+    #[must_use]
+    pub fn with_default_selected(mut self, default: impl Into<Cow<'a, str>>) -> Self {
+        let default = default.into();
+        self.position = default.chars().count();
+        self.value.set(default.clone());
+        self.default = Some(default);
+        self.selected = true;
+        self
+    }
+
+    /// The default value of the prompt, if any.
+    #[must_use]
+    pub fn default_value(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Whether the pre-filled default value is selected.
+    #[must_use]
+    pub const fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    /// Estimates the strength of the value with the given estimator, which shows a strength meter
+    /// below the input of a [`TextPrompt`].
+    ///
+    /// Use [`PasswordStrength::estimate`] for the built-in estimator.
+    #[must_use]
+    pub const fn with_password_strength(mut self, estimator: fn(&str) -> PasswordStrength) -> Self {
+        self.strength_estimator = Some(FnRef(estimator));
+        self
+    }
+
+    /// Prevents the prompt from completing while the password is weaker than the given strength.
+    ///
+    /// This also shows a strength meter using [`PasswordStrength::estimate`], unless an estimator
+    /// is set with [`TextState::with_password_strength`].
+    #[must_use]
+    pub fn with_min_strength(mut self, min_strength: PasswordStrength) -> Self {
+        self.min_strength = Some(min_strength);
+        self.strength_estimator
+            .get_or_insert(FnRef(PasswordStrength::estimate));
+        self
+    }
+
+    /// The estimated strength of the value, if a strength estimator is set.
+    #[must_use]
+    pub fn password_strength(&self) -> Option<PasswordStrength> {
+        self.strength_estimator
+            .map(|FnRef(estimate)| estimate(&self.value.text))
+    }
+
+    /// Whether the value is weaker than the minimum strength, if any.
+    #[must_use]
+    pub fn is_too_weak(&self) -> bool {
+        self.min_strength
+            .zip(self.password_strength())
+            .is_some_and(|(min, strength)| strength < min)
+    }
+
+    /// Sets the completer that provides candidates when Tab is pressed.
+    #[must_use]
+    pub fn with_completer(mut self, completer: impl Completer + Send + Sync + 'static) -> Self {
+        self.completer = Some(CompleterRef(Arc::new(completer)));
+        self
+    }
+
+    /// The candidates listed by the last completion, if it had more than one candidate.
+    #[must_use]
+    pub fn completions(&self) -> &[String] {
+        &self.completions
+    }
+
+    /// The index of the candidate that is currently applied to the value, if any.
+    #[must_use]
+    pub const fn completion_index(&self) -> Option<usize> {
+        self.completion_index
+    }
+
+    /// Completes the value, or applies the next candidate if the candidates are listed.
+    pub fn next_completion(&mut self) {
+        self.cycle_completion(true);
+    }
+
+    /// Completes the value, or applies the previous candidate if the candidates are listed.
+    pub fn previous_completion(&mut self) {
+        self.cycle_completion(false);
+    }
+
+    /// Clears the listed candidates.
+    pub fn clear_completions(&mut self) {
+        self.completions.clear();
+        self.completion_index = None;
+    }
+
+    fn cycle_completion(&mut self, forward: bool) {
+        if self.completions.is_empty() {
+            self.start_completion();
+            return;
+        }
+        let len = self.completions.len();
+        let index = match (self.completion_index, forward) {
+            (None, true) => 0,
+            (None, false) => len - 1,
+            (Some(index), true) => (index + 1) % len,
+            (Some(index), false) => (index + len - 1) % len,
+        };
+        self.completion_index = Some(index);
+        let candidate = self.completions[index].clone();
+        self.replace_before_cursor(&candidate);
+    }
+
+    /// Asks the completer for candidates, applies their common prefix and lists them if there is
+    /// more than one.
+    fn start_completion(&mut self) {
+        let Some(completer) = &self.completer else {
+            return;
+        };
+        let mut candidates = completer.0.complete(self.value(), self.position);
+        match candidates.len() {
+            0 => {}
+            1 => {
+                let candidate = candidates.remove(0);
+                self.replace_before_cursor(&candidate);
+            }
+            _ => {
+                let before_cursor: String = self.value().chars().take(self.position).collect();
+                let prefix = common_prefix(&candidates);
+                if prefix.len() > before_cursor.len() && prefix.starts_with(&before_cursor) {
+                    let prefix = prefix.to_string();
+                    self.replace_before_cursor(&prefix);
+                }
+                self.completions = candidates;
+            }
+        }
+    }
+
+    /// Replaces the part of the value before the cursor and moves the cursor to the end of the
+    /// replacement.
+    fn replace_before_cursor(&mut self, replacement: &str) {
+        let after_cursor: String = self.value().chars().skip(self.position).collect();
+        self.value
+            .set(Cow::Owned(format!("{replacement}{after_cursor}")));
+        self.position = replacement.chars().count();
+    }
+
+    /// Sets the previously entered values, from oldest to newest.
+    ///
+    /// While the cursor is at the end of the input, the most recent entry that starts with the
+    /// value is suggested as its continuation, and rendered as dimmed text after the cursor by a
+    /// [`TextPrompt`]. The suggestion is not part of the value until it is accepted.
+    #[must_use]
+    pub fn with_history<I, T>(mut self, history: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.history = history.into_iter().map(Into::into).collect();
         self
     }
 
+    /// The previously entered values, from oldest to newest.
+    #[must_use]
+    pub fn history(&self) -> &[Cow<'a, str>] {
+        &self.history
+    }
+
+    /// The continuation of the value suggested by the history, if any.
+    ///
+    /// Values are only continued when they are non-empty and not secret, and the cursor is at the
+    /// end of the value.
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&str> {
+        let value = self.value();
+        if value.is_empty() || self.is_secret() || self.position != self.len() {
+            return None;
+        }
+        self.history
+            .iter()
+            .rev()
+            .find_map(|entry| entry.strip_prefix(value))
+            .filter(|suggestion| !suggestion.is_empty())
+    }
+
+    /// Appends the suggestion to the value.
+    pub fn accept_suggestion(&mut self) {
+        if let Some(suggestion) = self.suggestion().map(ToString::to_string) {
+            suggestion.chars().for_each(|c| self.push(c));
+        }
+    }
+
+    /// Appends the next word of the suggestion to the value, including any separators before it.
+    pub fn accept_suggestion_word(&mut self) {
+        let Some(suggestion) = self.suggestion() else {
+            return;
+        };
+        let word = suggestion
+            .chars()
+            .skip_while(|c| !c.is_alphanumeric())
+            .take_while(|c| c.is_alphanumeric())
+            .count();
+        let separators = suggestion
+            .chars()
+            .take_while(|c| !c.is_alphanumeric())
+            .count();
+        let word: String = suggestion.chars().take(separators + word).collect();
+        word.chars().for_each(|c| self.push(c));
+    }
+
+    /// Constrains the value to the slots of the mask, e.g. `(###) ###-####` for a phone number.
+    ///
+    /// Characters that do not fit the slot at the cursor are rejected, and the literals of the
+    /// mask are added automatically. The cursor position counts the typed characters only, so
+    /// that moving the cursor skips over the literals. The prompt does not complete while the mask
+    /// is partially filled.
+    ///
+    /// The value is formatted with the literals of the mask, unless set otherwise with
+    /// [`TextState::with_masked_value`].
+    #[must_use]
+    pub fn with_mask(mut self, mask: impl Into<Mask>) -> Self {
+        self.mask = Some(mask.into());
+        self.apply_mask();
+        self
+    }
+
+    /// Sets whether the value of a masked prompt includes the literals of the mask.
+    #[must_use]
+    pub fn with_masked_value(mut self, masked_value: MaskedValue) -> Self {
+        self.masked_value = masked_value;
+        self.apply_mask();
+        self
+    }
+
+    /// The mask of the value, if any.
+    #[must_use]
+    pub const fn mask(&self) -> Option<&Mask> {
+        self.mask.as_ref()
+    }
+
+    /// The text returned by [`State::value`] when the value is masked.
+    #[must_use]
+    pub const fn masked_value(&self) -> MaskedValue {
+        self.masked_value
+    }
+
+    /// The typed characters of the value, without the literals of the mask, if any.
+    #[must_use]
+    pub fn raw_value(&self) -> Cow<'_, str> {
+        match &self.mask {
+            Some(mask) if self.masked_value == MaskedValue::Formatted => {
+                Cow::Owned(mask.raw(self.value()))
+            }
+            _ => Cow::Borrowed(self.value()),
+        }
+    }
+
+    /// The value with the literals of the mask, if any.
+    #[must_use]
+    pub fn formatted_value(&self) -> Cow<'_, str> {
+        match &self.mask {
+            Some(mask) if self.masked_value == MaskedValue::Raw => {
+                Cow::Owned(mask.format(self.value()))
+            }
+            _ => Cow::Borrowed(self.value()),
+        }
+    }
+
+    /// Whether all slots of the mask are filled, or the value is not masked.
+    #[must_use]
+    pub fn is_mask_complete(&self) -> bool {
+        self.mask
+            .as_ref()
+            .map_or(true, |mask| mask.is_complete(&self.raw_value()))
+    }
+
+    /// Reformats the value to fit the mask, if any.
+    fn apply_mask(&mut self) {
+        if let Some(mask) = &self.mask {
+            let raw = mask.raw(self.value());
+            self.set_masked(&raw);
+        }
+    }
+
+    /// Sets the raw value of a masked prompt, formatted as configured.
+    fn set_masked(&mut self, raw: &str) {
+        let Some(mask) = &self.mask else {
+            return;
+        };
+        let text = match self.masked_value {
+            MaskedValue::Formatted => mask.format(raw),
+            MaskedValue::Raw => raw.to_string(),
+        };
+        self.value.set(Cow::Owned(text));
+        self.position = self.position.min(raw.chars().count());
+    }
+
+    /// Applies an edit to the typed characters of a masked value.
+    ///
+    /// The edit is rejected if the result does not fit the mask. Returns whether the value is
+    /// masked, i.e. whether the edit was handled.
+    fn edit_masked(&mut self, edit: impl FnOnce(&mut Vec<char>, &mut usize)) -> bool {
+        let Some(mask) = &self.mask else {
+            return false;
+        };
+        let mut raw: Vec<char> = self.raw_value().chars().collect();
+        let mut position = self.position.min(raw.len());
+        edit(&mut raw, &mut position);
+        let raw: String = raw.into_iter().collect();
+        if mask.fits(&raw) {
+            self.position = position;
+            self.set_masked(&raw);
+        } else {
+            self.rejected = true;
+        }
+        true
+    }
+
+    /// Only accepts typed and pasted characters for which the filter returns true, e.g.
+    /// `|c| c.is_ascii_hexdigit()` or `|c| !c.is_whitespace()`.
+    #[must_use]
+    pub const fn with_char_filter(mut self, filter: fn(char) -> bool) -> Self {
+        self.char_filter = Some(FnRef(filter));
+        self
+    }
+
+    /// Limits the length of the value to the given number of graphemes (user-perceived
+    /// characters), rejecting typed and pasted characters beyond it.
+    #[must_use]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Converts typed and pasted characters to the given case.
+    #[must_use]
+    pub const fn with_case(mut self, case: TextCase) -> Self {
+        self.case = Some(case);
+        self
+    }
+
+    /// The maximum length of the value in graphemes, if any.
+    #[must_use]
+    pub const fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// The case that typed and pasted characters are converted to, if any.
+    #[must_use]
+    pub const fn case(&self) -> Option<TextCase> {
+        self.case
+    }
+
+    /// Whether input was rejected since the last key press, e.g. a letter typed into a prompt
+    /// that only accepts digits. A [`TextPrompt`] renders the value in the error style while this
+    /// is set.
+    #[must_use]
+    pub const fn is_rejected(&self) -> bool {
+        self.rejected
+    }
+
+    /// Checks the value when the prompt is completed, e.g.
+    /// `|value| value.contains('@').then_some(()).ok_or_else(|| "Not an email".into())`.
+    ///
+    /// The prompt stays pending while the validator returns an error, see [`TextState::error`].
+    #[must_use]
+    pub const fn with_validator(mut self, validator: Validator) -> Self {
+        self.validator = Some(FnRef(validator));
+        self
+    }
+
+    /// The error of the last validation, until the next key press.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Inserts pasted text at the cursor, e.g. from [`Event::Paste`].
+    ///
+    /// The text goes through the same constraints as typed characters. Characters that are
+    /// rejected are dropped while the rest is inserted. Line breaks and other control characters
+    /// are always dropped, and formatted text is reduced to the characters that fit the mask, if
+    /// any.
+    ///
+    /// [`Event::Paste`]: ratatui::crossterm::event::Event::Paste
+    pub fn paste(&mut self, text: &str) {
+        self.rejected = false;
+        self.last_typed = None;
+        self.clear_completions();
+        if self.selected {
+            self.selected = false;
+            self.truncate();
+        }
+        let text = self
+            .mask
+            .as_ref()
+            .map_or_else(|| text.to_string(), |mask| mask.raw(text));
+        text.chars()
+            .filter(|c| !c.is_control())
+            .for_each(|c| self.push(c));
+    }
+
+    /// Checks the value before the prompt is completed, returning the error to show, if any.
+    fn check(&self) -> Result<(), Option<String>> {
+        if let (true, Some(min)) = (self.is_too_weak(), self.min_strength) {
+            return Err(Some(format!("Password must be at least {}", min.label())));
+        }
+        if !(self.is_empty() || self.is_mask_complete()) {
+            // the unfilled slots of the mask already show what is missing
+            return Err(None);
+        }
+        match self.validator {
+            Some(FnRef(validator)) => validator(self.value()).map_err(Some),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the characters pass the filter and fit within the maximum length when inserted at
+    /// the cursor.
+    fn accepts(&self, chars: &[char]) -> bool {
+        if let Some(FnRef(filter)) = self.char_filter {
+            if !chars.iter().all(|&c| filter(c)) {
+                return false;
+            }
+        }
+        let Some(max_length) = self.max_length else {
+            return true;
+        };
+        // the position indexes the typed characters, without the literals of a mask, and combining
+        // characters extend the previous grapheme rather than adding one
+        let raw = self.raw_value();
+        let offset = raw
+            .char_indices()
+            .nth(self.position)
+            .map_or(raw.len(), |(offset, _)| offset);
+        let len = chars.iter().map(|c| c.len_utf8()).sum::<usize>();
+        let mut candidate = String::with_capacity(raw.len() + len);
+        candidate.push_str(&raw[..offset]);
+        candidate.extend(chars);
+        candidate.push_str(&raw[offset..]);
+        let fits = candidate.graphemes(true).count() <= max_length;
+        candidate.zeroize();
+        fits
+    }
+
+    /// Marks the state as rendered by a password prompt, which makes it secret and enables the
+    /// reveal toggle.
+    ///
+    /// This is set by a [`TextPrompt`] with [`TextRenderStyle::Password`] when it is rendered.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn set_password(&mut self, password: bool) {
+        if password {
+            self.value.secret = true;
+        }
+        self.reveal = match (password, self.reveal) {
+            (false, _) => Reveal::Disabled,
+            (true, Reveal::Disabled) => Reveal::Hidden,
+            (true, reveal) => reveal,
+        };
+    }
+
+    /// Whether the value of a password prompt is shown in plain text.
+    ///
+    /// A [`TextPrompt`] only shows the value while the prompt is pending, so that a finished prompt
+    /// never shows the password.
+    #[must_use]
+    pub const fn is_revealed(&self) -> bool {
+        matches!(self.reveal, Reveal::Shown)
+    }
+
+    /// Shows or hides the value of a password prompt.
+    pub fn toggle_reveal(&mut self) {
+        self.reveal = if self.is_revealed() {
+            Reveal::Hidden
+        } else {
+            Reveal::Shown
+        };
+    }
+
+    /// The index and time of the character typed by the last key event, if the last key event
+    /// typed a character.
+    #[must_use]
+    pub const fn last_typed(&self) -> Option<(usize, Instant)> {
+        self.last_typed
+    }
+
     #[must_use]
     pub const fn is_finished(&self) -> bool {
         self.status.is_finished()
@@ -81,17 +731,388 @@ impl State for TextState<'_> {
     }
 
     fn value(&self) -> &str {
-        &self.value
+        &self.value.text
     }
 
+    /// A mutable reference to the value of the prompt.
+    ///
+    /// Edits made through this reference bypass the zeroization of secret values.
     fn value_mut(&mut self) -> &mut String {
-        self.value.to_mut()
+        self.value.text.to_mut()
+    }
+
+    fn has_default(&self) -> bool {
+        self.default.is_some()
+    }
+
+    fn captures_key(&self, key_event: KeyEvent) -> bool {
+        matches!(key_event.code, KeyCode::Tab | KeyCode::BackTab) && self.completer.is_some()
+    }
+
+    fn handle_paste(&mut self, text: &str) {
+        self.paste(text);
+    }
+
+    /// The number of characters of the value, without the literals of the mask, if any.
+    fn len(&self) -> usize {
+        self.raw_value().chars().count()
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        let password = self.reveal != Reveal::Disabled;
+        if let (true, KeyCode::Char('r'), KeyModifiers::CONTROL)
+        | (true, KeyCode::Char('v'), KeyModifiers::ALT) =
+            (password, key_event.code, key_event.modifiers)
+        {
+            self.toggle_reveal();
+            return;
+        }
+        self.last_typed = None;
+        self.rejected = false;
+        self.error = None;
+        if self.selected {
+            self.selected = false;
+            match (key_event.code, key_event.modifiers) {
+                (KeyCode::Char(_), KeyModifiers::NONE | KeyModifiers::SHIFT) => self.truncate(),
+                (KeyCode::Backspace | KeyCode::Delete, _)
+                | (KeyCode::Char('h' | 'd'), KeyModifiers::CONTROL) => {
+                    self.truncate();
+                    return;
+                }
+                _ => {}
+            }
+        }
+        match key_event.code {
+            KeyCode::Tab if self.completer.is_some() => {
+                self.next_completion();
+                return;
+            }
+            KeyCode::BackTab if self.completer.is_some() => {
+                self.previous_completion();
+                return;
+            }
+            _ => self.clear_completions(),
+        }
+        if self.suggestion().is_some() {
+            match (key_event.code, key_event.modifiers) {
+                (KeyCode::Right | KeyCode::End, _)
+                | (KeyCode::Char('f' | 'e'), KeyModifiers::CONTROL) => {
+                    self.accept_suggestion();
+                    return;
+                }
+                (KeyCode::Char('f'), KeyModifiers::ALT) => {
+                    self.accept_suggestion_word();
+                    return;
+                }
+                _ => {}
+            }
+        }
+        let len = self.len();
+        handle_key_event(self, key_event);
+        if self.len() > len {
+            self.last_typed = Some((self.position.saturating_sub(1), Instant::now()));
+        }
+    }
+
+    fn complete(&mut self) {
+        let defaulted = self.value.text.is_empty() && self.default.is_some();
+        if let (true, Some(default)) = (defaulted, &self.default) {
+            self.value.set(default.clone());
+            self.position = self.len();
+        }
+        if let Err(error) = self.check() {
+            self.error = error;
+            // the default is only the value of a completed prompt
+            if defaulted {
+                self.value.truncate(0);
+                self.position = 0;
+            }
+            return;
+        }
+        self.selected = false;
+        self.status = Status::Done;
+    }
+
+    fn delete(&mut self) {
+        if self.position == self.len() {
+            return;
+        }
+        if !self.edit_masked(|raw, position| {
+            raw.remove(*position);
+        }) {
+            self.value.remove(self.position);
+        }
+    }
+
+    fn backspace(&mut self) {
+        if self.position == 0 {
+            return;
+        }
+        if !self.edit_masked(|raw, position| {
+            *position -= 1;
+            raw.remove(*position);
+        }) {
+            self.position -= 1;
+            self.value.remove(self.position);
+        }
+    }
+
+    fn kill(&mut self) {
+        if !self.edit_masked(|raw, position| raw.truncate(*position)) {
+            self.value.truncate(self.position);
+        }
+    }
+
+    fn truncate(&mut self) {
+        self.value.truncate(0);
+        self.position = 0;
+    }
+
+    /// Inserts the character at the cursor, converted to the case of the state, if it passes the
+    /// constraints of the state. Otherwise the input is flagged as rejected.
+    fn push(&mut self, c: char) {
+        let chars = self.case.map_or_else(|| vec![c], |case| case.convert(c));
+        // a conversion to several characters, e.g. `ß` to `SS`, is inserted whole or not at all
+        if !self.accepts(&chars) {
+            self.rejected = true;
+            return;
+        }
+        let masked = self.edit_masked(|raw, position| {
+            for &c in &chars {
+                raw.insert(*position, c);
+                *position += 1;
+            }
+        });
+        if !masked {
+            for c in chars {
+                self.value.insert(self.position, c);
+                self.position += 1;
+            }
+        }
+    }
+}
+
+/// A shared [`Completer`], compared and hashed by address.
+#[derive(Clone)]
+struct CompleterRef(Arc<dyn Completer + Send + Sync>);
+
+impl CompleterRef {
+    fn address(&self) -> *const () {
+        Arc::as_ptr(&self.0).cast()
+    }
+}
+
+impl fmt::Debug for CompleterRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Completer").field(&self.address()).finish()
+    }
+}
+
+impl PartialEq for CompleterRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.address() == other.address()
+    }
+}
+
+impl Eq for CompleterRef {}
+
+impl Hash for CompleterRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+    }
+}
+
+/// A function pointer, compared and hashed by address like a [`CompleterRef`].
+///
+/// The address of a function is not guaranteed to be unique, which is good enough to tell states
+/// apart.
+#[derive(Clone, Copy)]
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct FnRef<F>(pub(crate) F);
+
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) trait FnAddress: Copy {
+    fn address(self) -> usize;
+}
+
+impl<T> FnAddress for fn(&str) -> T {
+    fn address(self) -> usize {
+        self as usize
+    }
+}
+
+impl FnAddress for fn(char) -> bool {
+    fn address(self) -> usize {
+        self as usize
+    }
+}
+
+impl<F: FnAddress> fmt::Debug for FnRef<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fn({:#x})", self.0.address())
+    }
+}
+
+impl<F: FnAddress> PartialEq for FnRef<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.address() == other.0.address()
+    }
+}
+
+impl<F: FnAddress> Eq for FnRef<F> {}
+
+impl<F: FnAddress> Hash for FnRef<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.address().hash(state);
+    }
+}
+
+/// The value of a [`TextState`].
+///
+/// Edits are made in place rather than by collecting a new string. When the value is secret, the
+/// bytes left behind by edits, reallocations and drops are zeroized, and the value is hidden from
+/// `Debug` and `Hash`.
+#[derive(Clone, Default, PartialEq, Eq)]
+struct TextValue<'a> {
+    text: Cow<'a, str>,
+    secret: bool,
+}
+
+impl<'a> TextValue<'a> {
+    /// The capacity allocated for a secret value, to avoid reallocating while typing.
+    const SECRET_CAPACITY: usize = 64;
+
+    const fn new() -> Self {
+        Self {
+            text: Cow::Borrowed(""),
+            secret: false,
+        }
+    }
+
+    fn set(&mut self, text: Cow<'a, str>) {
+        self.zeroize();
+        self.text = text;
+    }
+
+    fn take(&mut self) -> String {
+        std::mem::take(&mut self.text).into_owned()
+    }
+
+    /// The byte offset of the character at the given index.
+    fn offset(&self, index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(index)
+            .map_or(self.text.len(), |(offset, _)| offset)
+    }
+
+    fn insert(&mut self, index: usize, c: char) {
+        let offset = self.offset(index);
+        self.reserve(c.len_utf8());
+        self.text.to_mut().insert(offset, c);
+    }
+
+    fn remove(&mut self, index: usize) {
+        let offset = self.offset(index);
+        self.text.to_mut().remove(offset);
+        self.zeroize_spare_capacity();
+    }
+
+    fn truncate(&mut self, index: usize) {
+        let offset = self.offset(index);
+        self.text.to_mut().truncate(offset);
+        self.zeroize_spare_capacity();
+    }
+
+    /// Reserves capacity for a secret value, zeroizing the old buffer if it has to grow.
+    ///
+    /// Non-secret values are left to grow as usual.
+    fn reserve(&mut self, additional: usize) {
+        if !self.secret {
+            return;
+        }
+        let text = self.text.to_mut();
+        if text.capacity() - text.len() >= additional {
+            return;
+        }
+        let capacity = (text.len() + additional)
+            .max(text.capacity() * 2)
+            .max(Self::SECRET_CAPACITY);
+        let mut grown = String::with_capacity(capacity);
+        grown.push_str(text);
+        text.zeroize();
+        *text = grown;
+    }
+
+    /// Zeroizes the bytes beyond the length of a secret value, which are left behind by removals.
+    fn zeroize_spare_capacity(&mut self) {
+        if !self.secret {
+            return;
+        }
+        if let Cow::Owned(text) = &mut self.text {
+            // SAFETY: only the spare capacity beyond the length of the string is written, so the
+            // string stays valid UTF-8
+            let bytes = unsafe { text.as_mut_vec() };
+            bytes.spare_capacity_mut().zeroize();
+        }
+    }
+
+    fn zeroize(&mut self) {
+        if !self.secret {
+            return;
+        }
+        if let Cow::Owned(text) = &mut self.text {
+            text.zeroize();
+        }
+    }
+}
+
+impl Drop for TextState<'_> {
+    fn drop(&mut self) {
+        if let (true, Some(Cow::Owned(default))) = (self.is_secret(), &mut self.default) {
+            default.zeroize();
+        }
+    }
+}
+
+impl fmt::Debug for TextValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.secret {
+            f.write_str("[REDACTED]")
+        } else {
+            fmt::Debug::fmt(&self.text, f)
+        }
+    }
+}
+
+impl Hash for TextValue<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.secret.hash(state);
+        if !self.secret {
+            self.text.hash(state);
+        }
+    }
+}
+
+impl Drop for TextValue<'_> {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{State, TextState};
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::{MaskedValue, PasswordStrength, State, Status, TextCase, TextState};
 
     #[test]
     fn insert_multibyte_start() {
@@ -171,4 +1192,501 @@ mod tests {
         assert_eq!(test.value(), "ä");
         assert_eq!(test.position(), 1);
     }
+
+    #[test]
+    fn validator() {
+        let mut test = TextState::new()
+            .with_validator(|value| {
+                (value.len() >= 3)
+                    .then_some(())
+                    .ok_or_else(|| "Too short".to_string())
+            })
+            .with_value("ab");
+        test.complete();
+        assert_eq!(test.status(), Status::Pending);
+        assert_eq!(test.error(), Some("Too short"));
+        test.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert_eq!(test.error(), None);
+        test.complete();
+        assert_eq!(test.status(), Status::Done);
+    }
+
+    #[test]
+    fn complete_with_default() {
+        let mut test = TextState::new().with_default("8080");
+        test.complete();
+        assert_eq!(test.value(), "8080");
+        assert_eq!(test.position(), 4);
+        assert_eq!(test.status(), Status::Done);
+    }
+
+    #[test]
+    fn complete_with_default_and_value() {
+        let mut test = TextState::new().with_default("8080").with_value("3000");
+        test.complete();
+        assert_eq!(test.value(), "3000");
+    }
+
+    #[test]
+    fn default_selected_replaced_by_typing() {
+        let mut test = TextState::new().with_default_selected("8080");
+        assert_eq!(test.value(), "8080");
+        assert!(test.is_selected());
+        test.handle_key_event(KeyEvent::from(KeyCode::Char('3')));
+        assert_eq!(test.value(), "3");
+        assert_eq!(test.position(), 1);
+        assert!(!test.is_selected());
+    }
+
+    #[test]
+    fn default_selected_cleared_by_backspace() {
+        let mut test = TextState::new().with_default_selected("8080");
+        test.handle_key_event(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(test.value(), "");
+        assert_eq!(test.position(), 0);
+        test.complete();
+        assert_eq!(test.value(), "8080");
+    }
+
+    #[test]
+    fn default_selected_kept_by_movement() {
+        let mut test = TextState::new().with_default_selected("8080");
+        test.handle_key_event(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::CONTROL));
+        test.handle_key_event(KeyEvent::from(KeyCode::Char('1')));
+        assert_eq!(test.value(), "80810");
+        assert!(!test.is_selected());
+    }
+
+    #[test]
+    fn toggle_reveal() {
+        let mut test = TextState::new().with_value("secret");
+        test.set_password(true);
+        assert!(!test.is_revealed());
+        test.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert!(test.is_revealed());
+        test.handle_key_event(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::ALT));
+        assert!(!test.is_revealed());
+        assert_eq!(test.value(), "secret");
+    }
+
+    #[test]
+    fn toggle_reveal_only_for_passwords() {
+        let mut test = TextState::new().with_value("secret");
+        test.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert!(!test.is_revealed());
+    }
+
+    #[test]
+    fn last_typed() {
+        let mut test = TextState::new().with_value("ab");
+        test.move_end();
+        test.handle_key_event(KeyEvent::from(KeyCode::Left));
+        assert_eq!(test.last_typed(), None);
+        test.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert_eq!(test.last_typed().map(|(index, _)| index), Some(1));
+        test.handle_key_event(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(test.last_typed(), None);
+    }
+
+    #[test]
+    fn kill_multibyte() {
+        let mut test = TextState::new().with_value("äëï");
+        test.move_right();
+        test.kill();
+        assert_eq!(test.value(), "ä");
+        assert_eq!(test.position(), 1);
+    }
+
+    #[test]
+    fn secret_edits() {
+        let mut test = TextState::new().with_secret(true).with_value("äë");
+        test.move_end();
+        test.push('ï');
+        test.backspace();
+        test.move_start();
+        test.delete();
+        assert_eq!(test.value(), "ë");
+        test.truncate();
+        assert_eq!(test.value(), "");
+    }
+
+    #[test]
+    fn secret_debug() {
+        let test = TextState::new().with_secret(true).with_value("hunter2");
+        let debug = format!("{test:?}");
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("hunter2"));
+    }
+
+    #[test]
+    fn secret_hash() {
+        fn hash(state: &TextState) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            state.hash(&mut hasher);
+            hasher.finish()
+        }
+        let first = TextState::new().with_secret(true).with_value("hunter2");
+        let second = TextState::new().with_secret(true).with_value("letmein");
+        assert_eq!(hash(&first), hash(&second));
+        let first = TextState::new().with_value("hunter2");
+        let second = TextState::new().with_value("letmein");
+        assert_ne!(hash(&first), hash(&second));
+    }
+
+    #[test]
+    fn take_secret() {
+        let mut test = TextState::new().with_secret(true);
+        for c in "hunter2".chars() {
+            test.push(c);
+        }
+        let secret = test.take_secret();
+        assert_eq!(secret.expose_secret(), "hunter2");
+        assert_eq!(test.value(), "");
+        assert_eq!(test.position(), 0);
+    }
+
+    #[test]
+    fn password_strength() {
+        let test = TextState::new().with_value("hunter2");
+        assert_eq!(test.password_strength(), None);
+        let test = test.with_password_strength(PasswordStrength::estimate);
+        assert_eq!(test.password_strength(), Some(PasswordStrength::Weak));
+        assert!(!test.is_too_weak());
+    }
+
+    #[test]
+    fn min_strength_blocks_completion() {
+        let mut test = TextState::new()
+            .with_min_strength(PasswordStrength::Fair)
+            .with_value("hunter2");
+        assert!(test.is_too_weak());
+        test.complete();
+        assert_eq!(test.status(), Status::Pending);
+        assert_eq!(test.error(), Some("Password must be at least fair"));
+
+        let mut test = test.with_value("correct horse battery staple");
+        test.complete();
+        assert_eq!(test.status(), Status::Done);
+    }
+
+    #[test]
+    fn min_strength_blocks_default() {
+        let mut test = TextState::new()
+            .with_min_strength(PasswordStrength::Fair)
+            .with_default("hunter2");
+        test.complete();
+        assert_eq!(test.status(), Status::Pending);
+        assert_eq!(test.value(), "");
+        assert!(test.error().is_some());
+    }
+
+    fn colors(value: &str, position: usize) -> Vec<String> {
+        let before_cursor: String = value.chars().take(position).collect();
+        ["black", "blue", "brown", "red"]
+            .iter()
+            .filter(|color| color.starts_with(&before_cursor))
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn tab_without_completer() {
+        let mut test = TextState::new().with_value("b");
+        test.move_end();
+        test.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(test.value(), "b");
+        assert!(test.completions().is_empty());
+    }
+
+    #[test]
+    fn tab_single_candidate() {
+        let mut test = TextState::new().with_completer(colors).with_value("r");
+        test.move_end();
+        test.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(test.value(), "red");
+        assert_eq!(test.position(), 3);
+        assert!(test.completions().is_empty());
+    }
+
+    #[test]
+    fn tab_common_prefix_then_cycle() {
+        let mut test = TextState::new().with_completer(colors).with_value("b");
+        test.move_end();
+        test.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(test.value(), "b");
+        assert_eq!(test.completions(), ["black", "blue", "brown"]);
+        assert_eq!(test.completion_index(), None);
+
+        test.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(test.value(), "black");
+        assert_eq!(test.completion_index(), Some(0));
+        test.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(test.value(), "blue");
+        test.handle_key_event(KeyCode::BackTab.into());
+        test.handle_key_event(KeyCode::BackTab.into());
+        assert_eq!(test.value(), "brown");
+        assert_eq!(test.position(), 5);
+
+        test.handle_key_event(KeyCode::Char('s').into());
+        assert_eq!(test.value(), "browns");
+        assert!(test.completions().is_empty());
+    }
+
+    #[test]
+    fn tab_common_prefix_applied() {
+        let mut test = TextState::new().with_completer(colors).with_value("bl");
+        test.move_end();
+        test.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(test.completions(), ["black", "blue"]);
+        assert_eq!(test.value(), "bl");
+
+        let completer = |_: &str, _: usize| vec!["prefix-a".to_string(), "prefix-b".to_string()];
+        let mut test = TextState::new().with_completer(completer).with_value("p");
+        test.move_end();
+        test.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(test.value(), "prefix-");
+        assert_eq!(test.position(), 7);
+    }
+
+    #[test]
+    fn tab_keeps_text_after_cursor() {
+        let mut test = TextState::new().with_completer(colors).with_value("r!");
+        test.move_start();
+        test.move_right();
+        test.handle_key_event(KeyCode::Tab.into());
+        assert_eq!(test.value(), "red!");
+        assert_eq!(test.position(), 3);
+    }
+
+    const HISTORY: [&str; 3] = ["git commit -m fix", "cargo test", "git checkout main"];
+
+    fn with_history(value: &str) -> TextState<'static> {
+        let mut test = TextState::new()
+            .with_history(HISTORY)
+            .with_value(value.to_string());
+        test.move_end();
+        test
+    }
+
+    #[test]
+    fn suggestion_most_recent() {
+        assert_eq!(with_history("git c").suggestion(), Some("heckout main"));
+        assert_eq!(with_history("git co").suggestion(), Some("mmit -m fix"));
+        assert_eq!(with_history("cargo").suggestion(), Some(" test"));
+    }
+
+    #[test]
+    fn no_suggestion() {
+        assert_eq!(with_history("").suggestion(), None);
+        assert_eq!(with_history("cargo test").suggestion(), None);
+        assert_eq!(with_history("npm").suggestion(), None);
+        assert_eq!(with_history("cargo").with_secret(true).suggestion(), None);
+
+        let mut test = with_history("cargo");
+        test.move_left();
+        assert_eq!(test.suggestion(), None);
+    }
+
+    #[test]
+    fn suggestion_not_in_value() {
+        let test = with_history("cargo");
+        assert_eq!(test.value(), "cargo");
+        assert_eq!(test.len(), 5);
+    }
+
+    #[test]
+    fn accept_suggestion() {
+        for key in [
+            KeyCode::Right.into(),
+            KeyCode::End.into(),
+            KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL),
+        ] {
+            let mut test = with_history("cargo");
+            test.handle_key_event(key);
+            assert_eq!(test.value(), "cargo test");
+            assert_eq!(test.position(), 10);
+        }
+    }
+
+    #[test]
+    fn accept_suggestion_word() {
+        let mut test = with_history("git co");
+        let alt_f = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::ALT);
+        test.handle_key_event(alt_f);
+        assert_eq!(test.value(), "git commit");
+        test.handle_key_event(alt_f);
+        assert_eq!(test.value(), "git commit -m");
+        test.handle_key_event(alt_f);
+        assert_eq!(test.value(), "git commit -m fix");
+        assert_eq!(test.suggestion(), None);
+    }
+
+    #[test]
+    fn right_without_suggestion_moves() {
+        let mut test = with_history("cargo");
+        test.move_start();
+        test.handle_key_event(KeyCode::Right.into());
+        assert_eq!(test.value(), "cargo");
+        assert_eq!(test.position(), 1);
+    }
+
+    fn phone() -> TextState<'static> {
+        TextState::new().with_mask("(###) ###-####")
+    }
+
+    #[test]
+    fn mask_formats_value() {
+        let mut test = phone();
+        for c in "5551".chars() {
+            test.push(c);
+        }
+        assert_eq!(test.value(), "(555) 1");
+        assert_eq!(test.raw_value(), "5551");
+        assert_eq!(test.position(), 4);
+        assert_eq!(test.len(), 4);
+    }
+
+    #[test]
+    fn mask_rejects_characters() {
+        let mut test = phone();
+        test.push('x');
+        assert_eq!(test.value(), "");
+        for c in "55512345678".chars() {
+            test.push(c);
+        }
+        assert_eq!(test.value(), "(555) 123-4567");
+        assert_eq!(test.position(), 10);
+    }
+
+    #[test]
+    fn mask_edits_raw_value() {
+        let mut test = phone().with_value("555-123");
+        assert_eq!(test.value(), "(555) 123");
+        test.move_start();
+        test.move_right();
+        test.move_right();
+        test.move_right();
+        test.backspace();
+        assert_eq!(test.value(), "(551) 23");
+        test.delete();
+        assert_eq!(test.value(), "(552) 3");
+        test.kill();
+        assert_eq!(test.value(), "(55");
+    }
+
+    #[test]
+    fn mask_raw_value() {
+        let test = phone()
+            .with_masked_value(MaskedValue::Raw)
+            .with_value("(555) 123-4567");
+        assert_eq!(test.value(), "5551234567");
+        assert_eq!(test.formatted_value(), "(555) 123-4567");
+    }
+
+    #[test]
+    fn mask_incomplete_does_not_complete() {
+        let mut test = phone().with_value("555");
+        test.complete();
+        assert_eq!(test.status(), Status::Pending);
+        test.truncate();
+        test.complete();
+        assert_eq!(test.status(), Status::Done);
+    }
+
+    #[test]
+    fn char_filter() {
+        let mut test = TextState::new().with_char_filter(|c| c.is_ascii_hexdigit());
+        test.handle_key_event(KeyCode::Char('f').into());
+        assert!(!test.is_rejected());
+        test.handle_key_event(KeyCode::Char('g').into());
+        assert_eq!(test.value(), "f");
+        assert!(test.is_rejected());
+        test.handle_key_event(KeyCode::Char('0').into());
+        assert_eq!(test.value(), "f0");
+        assert!(!test.is_rejected());
+    }
+
+    #[test]
+    fn max_length_counts_graphemes() {
+        let mut test = TextState::new().with_max_length(2);
+        for c in "ae\u{301}".chars() {
+            test.push(c);
+        }
+        assert_eq!(test.value(), "ae\u{301}");
+        assert!(!test.is_rejected());
+        test.push('x');
+        assert_eq!(test.value(), "ae\u{301}");
+        assert!(test.is_rejected());
+    }
+
+    #[test]
+    fn max_length_counts_typed_characters_of_mask() {
+        let mut test = phone().with_max_length(4);
+        for c in "5551".chars() {
+            test.push(c);
+        }
+        assert_eq!(test.value(), "(555) 1");
+        assert!(!test.is_rejected());
+        test.push('2');
+        assert_eq!(test.value(), "(555) 1");
+        assert!(test.is_rejected());
+    }
+
+    #[test]
+    fn case() {
+        let mut test = TextState::new().with_case(TextCase::Upper);
+        test.push('a');
+        test.push('ß');
+        assert_eq!(test.value(), "ASS");
+        let mut test = TextState::new().with_case(TextCase::Lower);
+        test.paste("AbC");
+        assert_eq!(test.value(), "abc");
+    }
+
+    #[test]
+    fn case_conversion_is_inserted_whole() {
+        let mut test = TextState::new()
+            .with_case(TextCase::Upper)
+            .with_max_length(1);
+        test.push('ß');
+        assert_eq!(test.value(), "");
+        assert!(test.is_rejected());
+    }
+
+    #[test]
+    fn paste() {
+        let mut test = TextState::new()
+            .with_char_filter(|c| !c.is_whitespace())
+            .with_max_length(6)
+            .with_value("ab");
+        test.move_end();
+        test.paste("c d\nefgh");
+        assert_eq!(test.value(), "abcdef");
+        assert_eq!(test.position(), 6);
+        assert!(test.is_rejected());
+    }
+
+    #[test]
+    fn paste_replaces_selected_default() {
+        let mut test = TextState::new().with_default_selected("default");
+        test.paste("pasted");
+        assert_eq!(test.value(), "pasted");
+        assert!(!test.is_selected());
+    }
+
+    #[test]
+    fn paste_into_mask() {
+        let mut test = phone();
+        test.paste("(555) 123-4567");
+        assert_eq!(test.value(), "(555) 123-4567");
+        assert!(!test.is_rejected());
+    }
+
+    #[test]
+    fn mask_rejection_is_flagged() {
+        let mut test = phone();
+        test.handle_key_event(KeyCode::Char('x').into());
+        assert!(test.is_rejected());
+    }
 }
@@ -0,0 +1,254 @@
+use std::borrow::Cow;
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+use crate::Symbols;
+
+/// The symbols and styles used to render prompts.
+///
+/// Every prompt has a theme, which defaults to [`Theme::new`]. Use the `with_theme` method of a
+/// prompt to change it. [`Theme::ascii`] and [`Theme::no_color`] provide fallbacks for terminals
+/// that cannot display the default symbols or colors, and [`Theme::from_env`] selects them at
+/// runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{style::Stylize, text::Span};
+/// use tui_prompts::{prelude::*, Symbols, Theme};
+///
+/// let theme = Theme {
+///     symbols: Symbols {
+///         pending: "›".magenta(),
+///         ..Symbols::default()
+///     },
+///     separator: Span::raw(": "),
+///     ..Theme::default()
+/// };
+/// let prompt = TextPrompt::from("Name").with_theme(theme);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// The symbols displayed before the message to indicate the status of the prompt.
+    pub symbols: Symbols,
+    /// The style of the message.
+    pub message: Style,
+    /// The separator between the message and the value.
+    pub separator: Span<'static>,
+    /// The style of the value.
+    pub value: Style,
+    /// The style of text that is shown in place of, or after, the value but is not part of it.
+    pub placeholder: Style,
+    /// The style of error messages and rejected input.
+    pub error: Style,
+    /// The style of hints, such as the default value.
+    pub hint: Style,
+    /// The style of highlighted characters, such as the characters matching a filter.
+    pub highlight: Style,
+    /// The filled and empty segments of the password strength meter.
+    pub meter: [&'static str; 2],
+    /// The styles of the password strength meter, indexed by [`PasswordStrength::score`].
+    ///
+    /// [`PasswordStrength::score`]: crate::PasswordStrength::score
+    pub strength: [Style; 5],
+}
+
+impl Theme {
+    /// The default theme.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            symbols: Symbols::new(),
+            message: Style::new().add_modifier(Modifier::BOLD),
+            separator: Span {
+                content: Cow::Borrowed(" › "),
+                style: Style::new().fg(Color::Cyan).add_modifier(Modifier::DIM),
+            },
+            value: Style::new(),
+            placeholder: Style::new().add_modifier(Modifier::DIM),
+            error: Style::new().fg(Color::Red),
+            hint: Style::new().add_modifier(Modifier::DIM),
+            highlight: Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            meter: ["■", "□"],
+            strength: STRENGTH_STYLES,
+        }
+    }
+
+    /// The default theme using only ASCII characters.
+    ///
+    /// The status symbols are replaced with [`Symbols::ascii`], the separator with ` > ` and the
+    /// meter segments with `#` and `-`.
+    #[must_use]
+    pub const fn ascii() -> Self {
+        Self {
+            symbols: Symbols::ascii(),
+            separator: Span {
+                content: Cow::Borrowed(" > "),
+                style: Style::new().fg(Color::Cyan).add_modifier(Modifier::DIM),
+            },
+            message: Style::new().add_modifier(Modifier::BOLD),
+            value: Style::new(),
+            placeholder: Style::new().add_modifier(Modifier::DIM),
+            error: Style::new().fg(Color::Red),
+            hint: Style::new().add_modifier(Modifier::DIM),
+            highlight: Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            meter: ["#", "-"],
+            strength: STRENGTH_STYLES,
+        }
+    }
+
+    /// Removes all styles from the theme, keeping the symbols and separator text.
+    #[must_use]
+    pub const fn no_color(mut self) -> Self {
+        self.symbols.pending.style = Style::new();
+        self.symbols.aborted.style = Style::new();
+        self.symbols.done.style = Style::new();
+        self.message = Style::new();
+        self.separator.style = Style::new();
+        self.value = Style::new();
+        self.placeholder = Style::new();
+        self.error = Style::new();
+        self.hint = Style::new();
+        self.highlight = Style::new();
+        self.strength = [Style::new(); 5];
+        self
+    }
+
+    /// A theme selected from the environment.
+    ///
+    /// - `NO_COLOR` set to a non-empty value removes all styles (see <https://no-color.org>).
+    /// - `TERM=dumb` uses ASCII characters and removes all styles.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let no_color = var("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let dumb = var("TERM").is_some_and(|value| value == "dumb");
+        match (no_color, dumb) {
+            (_, true) => Self::ascii().no_color(),
+            (true, false) => Self::new().no_color(),
+            (false, false) => Self::new(),
+        }
+    }
+
+    /// Spans of the text in the value style, with the characters at the matched positions in the
+    /// highlight style.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) fn highlight_matches(&self, text: &str, positions: &[usize]) -> Vec<Span<'static>> {
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        for (index, c) in text.chars().enumerate() {
+            let style = if positions.contains(&index) {
+                self.highlight
+            } else {
+                self.value
+            };
+            match spans.last_mut() {
+                Some(span) if span.style == style => span.content.to_mut().push(c),
+                _ => spans.push(Span::styled(c.to_string(), style)),
+            }
+        }
+        spans
+    }
+}
+
+const STRENGTH_STYLES: [Style; 5] = [
+    Style::new().fg(Color::Red),
+    Style::new().fg(Color::LightRed),
+    Style::new().fg(Color::Yellow),
+    Style::new().fg(Color::Green),
+    Style::new().fg(Color::LightGreen),
+];
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn default() {
+        let theme = Theme::default();
+        assert_eq!(theme.symbols, Symbols::default());
+        assert_eq!(theme.message, Style::new().bold());
+        assert_eq!(theme.separator, " › ".cyan().dim());
+        assert_eq!(theme.value, Style::new());
+        assert_eq!(theme.placeholder, Style::new().dim());
+        assert_eq!(theme.error, Style::new().red());
+        assert_eq!(theme.hint, Style::new().dim());
+        assert_eq!(theme.highlight, Style::new().cyan().bold());
+        assert_eq!(theme.meter, ["■", "□"]);
+        assert_eq!(theme.strength[0], Style::new().red());
+    }
+
+    #[test]
+    fn ascii() {
+        let theme = Theme::ascii();
+        assert_eq!(theme.symbols, Symbols::ascii());
+        assert_eq!(theme.separator, " > ".cyan().dim());
+        assert_eq!(theme.message, Style::new().bold());
+        assert_eq!(theme.meter, ["#", "-"]);
+    }
+
+    #[test]
+    fn no_color() {
+        let theme = Theme::default().no_color();
+        assert_eq!(theme.symbols.pending, Span::raw("?"));
+        assert_eq!(theme.symbols.aborted, Span::raw("✘"));
+        assert_eq!(theme.symbols.done, Span::raw("✔"));
+        assert_eq!(theme.separator, Span::raw(" › "));
+        assert_eq!(theme.message, Style::new());
+        assert_eq!(theme.value, Style::new());
+        assert_eq!(theme.placeholder, Style::new());
+        assert_eq!(theme.error, Style::new());
+        assert_eq!(theme.hint, Style::new());
+        assert_eq!(theme.highlight, Style::new());
+        assert_eq!(theme.strength, [Style::new(); 5]);
+    }
+
+    fn vars<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).to_string())
+        }
+    }
+
+    #[test]
+    fn from_vars() {
+        assert_eq!(Theme::from_vars(vars(&[])), Theme::new());
+        assert_eq!(Theme::from_vars(vars(&[("TERM", "xterm")])), Theme::new());
+        assert_eq!(Theme::from_vars(vars(&[("NO_COLOR", "")])), Theme::new());
+        assert_eq!(
+            Theme::from_vars(vars(&[("NO_COLOR", "1")])),
+            Theme::new().no_color()
+        );
+        assert_eq!(
+            Theme::from_vars(vars(&[("TERM", "dumb")])),
+            Theme::ascii().no_color()
+        );
+    }
+
+    #[test]
+    fn highlight_matches() {
+        let theme = Theme::new();
+        assert_eq!(
+            theme.highlight_matches("prompt", &[0, 1, 5]),
+            [
+                Span::styled("pr", theme.highlight),
+                Span::styled("omp", theme.value),
+                Span::styled("t", theme.highlight),
+            ]
+        );
+    }
+}
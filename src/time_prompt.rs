@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+
+use crate::{prelude::*, segment};
+
+use ratatui::{prelude::*, widgets::StatefulWidget};
+
+/// A prompt widget that asks for a time of day.
+///
+/// The time is rendered on the first line, with the focused segment reversed while the prompt is
+/// pending, followed by the error of the typed time, if any. See [`TimeState`] for the behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimePrompt<'a> {
+    /// The message to display before the time.
+    message: Cow<'a, str>,
+    /// The symbols and styles used to render the prompt.
+    theme: Theme,
+}
+
+impl<'a> TimePrompt<'a> {
+    #[must_use]
+    pub const fn new(message: Cow<'a, str>) -> Self {
+        Self {
+            message,
+            theme: Theme::new(),
+        }
+    }
+
+    #[must_use]
+    // const causes: error[E0493]: destructor of `Theme` cannot be evaluated at compile-time
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+impl Prompt for TimePrompt<'_> {
+    /// Draws the prompt widget.
+    ///
+    /// This is in addition to the `Widget` trait implementation as we need the `Frame` to set the
+    /// cursor position.
+    fn draw(self, frame: &mut Frame, area: Rect, state: &mut Self::State) {
+        frame.render_stateful_widget(self, area, state);
+        if state.is_focused() {
+            frame.set_cursor(state.cursor().0, state.cursor().1);
+        }
+    }
+}
+
+impl<'a> StatefulWidget for TimePrompt<'a> {
+    type State = TimeState<'a>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let range = state.segment_range();
+        let error = state.error().map(str::to_string);
+        let input_prompt = TextPrompt::new(self.message).with_theme(self.theme.clone());
+        segment::render(
+            input_prompt,
+            &self.theme,
+            area,
+            buf,
+            state.input_mut(),
+            range,
+            error.as_deref(),
+        );
+    }
+}
+
+impl<T> From<T> for TimePrompt<'static>
+where
+    T: Into<Cow<'static, str>>,
+{
+    fn from(message: T) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+    use time::macros::time;
+
+    use super::*;
+
+    fn render<'a>(prompt: TimePrompt<'a>, state: &mut TimeState<'a>) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 2));
+        prompt.render(buffer.area, &mut buffer, state);
+        buffer
+    }
+
+    fn unstyled(mut buffer: Buffer) -> Buffer {
+        buffer.set_style(buffer.area, Style::reset());
+        buffer
+    }
+
+    #[test]
+    fn new() {
+        let prompt = TimePrompt::from("Time");
+        assert_eq!(prompt.message, "Time");
+        assert_eq!(prompt.theme, Theme::default());
+    }
+
+    #[test]
+    fn render_segment() {
+        let prompt = TimePrompt::from("Time");
+        let mut state = TimeState::from(time!(14:30)).with_twelve_hour(true);
+        state.handle_key_event(KeyCode::Right.into());
+        let buffer = render(prompt, &mut state);
+        // the minutes are reversed, the hours are not
+        assert!(!buffer.get(9, 0).modifier.contains(Modifier::REVERSED));
+        assert!(buffer.get(12, 0).modifier.contains(Modifier::REVERSED));
+        assert!(buffer.get(13, 0).modifier.contains(Modifier::REVERSED));
+        assert!(!buffer.get(14, 0).modifier.contains(Modifier::REVERSED));
+        assert_eq!(
+            unstyled(buffer),
+            Buffer::with_lines(["? Time › 02:30 PM             ", ""])
+        );
+    }
+
+    #[test]
+    fn render_error() {
+        let prompt = TimePrompt::from("Time");
+        let mut state = TimeState::from(time!(14:30));
+        state.handle_key_event(KeyCode::Char('x').into());
+        state.handle_key_event(KeyCode::Enter.into());
+        let buffer = render(prompt, &mut state);
+        // nothing is reversed while typing
+        assert!(!buffer.get(9, 0).modifier.contains(Modifier::REVERSED));
+        assert_eq!(
+            unstyled(buffer),
+            Buffer::with_lines(["? Time › x                    ", "Invalid time, e.g. 14:30"])
+        );
+    }
+
+    #[test]
+    fn render_done() {
+        let prompt = TimePrompt::from("Time");
+        let mut state = TimeState::from(time!(14:30));
+        state.handle_key_event(KeyCode::Enter.into());
+        let buffer = render(prompt, &mut state);
+        assert!(!buffer.get(9, 0).modifier.contains(Modifier::REVERSED));
+        assert_eq!(
+            unstyled(buffer),
+            Buffer::with_lines(["✔ Time › 14:30                ", ""])
+        );
+    }
+}
@@ -0,0 +1,488 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use time::Time;
+
+use crate::{
+    prelude::*,
+    segment::{self, Segment, SegmentEditor, Segmented},
+    State,
+};
+
+/// The state of a [`TimePrompt`].
+///
+/// The time is edited one segment at a time: the hours, the minutes, the seconds (see
+/// [`TimeState::with_seconds`]) and AM / PM (see [`TimeState::with_twelve_hour`]). Typing a
+/// character replaces the time with the typed text, e.g. `14:30`, `2:30 pm` or `14:30:15`, which
+/// is parsed when moving to another segment or completing the prompt.
+///
+/// Keybindings in addition to the editing keybindings of [`TextState`]:
+/// - Left / Right: Focus the previous / next segment
+/// - Up / Down: Increment / decrement the focused segment, rolling over at its bounds
+/// - Enter: Complete the prompt
+///
+/// The prompt only becomes [`Status::Done`] if the typed time is valid. Otherwise an error is
+/// shown until the next key press.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeState<'a> {
+    status: Status,
+    focus: FocusState,
+    editor: SegmentEditor<'a>,
+    value: TimeValue,
+}
+
+/// A time with the segments it is edited in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct TimeValue {
+    time: Time,
+    seconds: bool,
+    twelve_hour: bool,
+}
+
+impl Default for TimeState<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeState<'_> {
+    /// Creates a state for a 24-hour time without seconds, starting at midnight.
+    #[must_use]
+    pub fn new() -> Self {
+        let value = TimeValue {
+            time: Time::MIDNIGHT,
+            seconds: false,
+            twelve_hour: false,
+        };
+        Self {
+            status: Status::Pending,
+            focus: FocusState::Unfocused,
+            editor: SegmentEditor::new(&value),
+            value,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_focus(mut self, focus: FocusState) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    /// Sets the time.
+    #[must_use]
+    pub fn with_time(mut self, time: Time) -> Self {
+        self.value.time = time;
+        self.editor.sync(&self.value);
+        self
+    }
+
+    /// Sets whether the seconds are edited as well.
+    ///
+    /// Without seconds, the seconds of the time are dropped.
+    #[must_use]
+    pub fn with_seconds(mut self, seconds: bool) -> Self {
+        self.value.seconds = seconds;
+        if !seconds {
+            self.value.time = self.value.time.replace_second(0).unwrap_or(self.value.time);
+        }
+        self.editor.sync(&self.value);
+        self
+    }
+
+    /// Sets whether the time is shown on a 12-hour clock with AM / PM.
+    #[must_use]
+    pub fn with_twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.value.twelve_hour = twelve_hour;
+        self.editor.sync(&self.value);
+        self
+    }
+
+    /// The time, which is the entered time once the prompt is done.
+    ///
+    /// Typed text only changes the time once it is parsed.
+    #[must_use]
+    pub const fn time(&self) -> Time {
+        self.value.time
+    }
+
+    /// Whether the seconds are edited as well.
+    #[must_use]
+    pub const fn is_seconds(&self) -> bool {
+        self.value.seconds
+    }
+
+    /// Whether the time is shown on a 12-hour clock.
+    #[must_use]
+    pub const fn is_twelve_hour(&self) -> bool {
+        self.value.twelve_hour
+    }
+
+    /// The index of the focused segment.
+    #[must_use]
+    pub const fn segment(&self) -> usize {
+        self.editor.segment()
+    }
+
+    /// Whether the time is being typed rather than edited segment by segment.
+    #[must_use]
+    pub const fn is_typing(&self) -> bool {
+        self.editor.is_typing()
+    }
+
+    /// The character range of the focused segment in the value, unless the time is being typed.
+    #[must_use]
+    pub fn segment_range(&self) -> Option<std::ops::Range<usize>> {
+        self.editor.segment_range(&self.value)
+    }
+
+    /// The error of the typed time, if it was invalid.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.editor.error()
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+}
+
+impl<'a> TimeState<'a> {
+    /// A mutable reference to the state of the text input.
+    pub fn input_mut(&mut self) -> &mut TextState<'a> {
+        &mut self.editor.input
+    }
+}
+
+impl Segmented for TimeValue {
+    fn segments(&self) -> Vec<Segment> {
+        let (hour, minute, second) = self.time.as_hms();
+        let shown_hour = if self.twelve_hour {
+            (hour + 11) % 12 + 1
+        } else {
+            hour
+        };
+        let mut segments = vec![Segment::new(format!("{shown_hour:02}"), ":")];
+        let separator = if self.seconds {
+            ":"
+        } else if self.twelve_hour {
+            " "
+        } else {
+            ""
+        };
+        segments.push(Segment::new(format!("{minute:02}"), separator));
+        if self.seconds {
+            segments.push(Segment::new(
+                format!("{second:02}"),
+                if self.twelve_hour { " " } else { "" },
+            ));
+        }
+        if self.twelve_hour {
+            segments.push(Segment::new(if hour < 12 { "AM" } else { "PM" }, ""));
+        }
+        segments
+    }
+
+    fn step(&mut self, segment: usize, delta: i64) {
+        let (mut hour, mut minute, mut second) = self.time.as_hms();
+        let roll = |value: u8, len: i64| {
+            u8::try_from((i64::from(value) + delta).rem_euclid(len)).unwrap_or(value)
+        };
+        match segment {
+            // on a 12-hour clock the hours roll over from 12 to 1 without changing AM / PM
+            0 if self.twelve_hour => hour = roll(hour % 12, 12) + hour / 12 * 12,
+            0 => hour = roll(hour, 24),
+            1 => minute = roll(minute, 60),
+            2 if self.seconds => second = roll(second, 60),
+            _ => hour = (hour + 12) % 24,
+        }
+        if let Ok(time) = Time::from_hms(hour, minute, second) {
+            self.time = time;
+        }
+    }
+
+    fn parse(&mut self, text: &str) -> Result<(), String> {
+        let time = parse_time(text, self.twelve_hour)
+            .ok_or_else(|| format!("Invalid time, e.g. {}", segment::join(&self.segments())))?;
+        self.time = if self.seconds {
+            time
+        } else {
+            time.replace_second(0).unwrap_or(time)
+        };
+        Ok(())
+    }
+}
+
+/// Parses `HH:MM[:SS]`, followed by AM / PM on a 12-hour clock.
+fn parse_time(text: &str, twelve_hour: bool) -> Option<Time> {
+    let text = text.trim().to_ascii_lowercase();
+    let (text, pm) = if twelve_hour {
+        if let Some(text) = text.strip_suffix("am") {
+            (text.trim_end(), false)
+        } else {
+            (text.strip_suffix("pm")?.trim_end(), true)
+        }
+    } else {
+        (text.as_str(), false)
+    };
+    let parts = text
+        .split(':')
+        .map(|part| {
+            let digits = (1..=2).contains(&part.len()) && part.chars().all(|c| c.is_ascii_digit());
+            digits.then(|| part.parse::<u8>().ok()).flatten()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let (hour, minute, second) = match parts[..] {
+        [hour, minute] => (hour, minute, 0),
+        [hour, minute, second] => (hour, minute, second),
+        _ => return None,
+    };
+    let hour = if twelve_hour {
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        hour % 12 + if pm { 12 } else { 0 }
+    } else {
+        hour
+    };
+    Time::from_hms(hour, minute, second).ok()
+}
+
+impl State for TimeState<'_> {
+    fn status(&self) -> Status {
+        self.status
+    }
+
+    fn status_mut(&mut self) -> &mut Status {
+        &mut self.status
+    }
+
+    fn focus_state_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    fn focus_state(&self) -> FocusState {
+        self.focus
+    }
+
+    fn position(&self) -> usize {
+        self.editor.input.position()
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        self.editor.input.position_mut()
+    }
+
+    fn cursor(&self) -> (u16, u16) {
+        self.editor.input.cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut (u16, u16) {
+        self.editor.input.cursor_mut()
+    }
+
+    /// The formatted or typed time. See [`TimeState::time`] for the entered time.
+    fn value(&self) -> &str {
+        self.editor.input.value()
+    }
+
+    fn value_mut(&mut self) -> &mut String {
+        self.editor.input.value_mut()
+    }
+
+    /// The prompt always holds a time, which starts at its initial value.
+    fn has_default(&self) -> bool {
+        true
+    }
+
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        if key_event.code == KeyCode::Enter {
+            self.complete();
+            return;
+        }
+        self.editor.handle_key_event(key_event, &mut self.value);
+        if self.editor.input.status().is_aborted() {
+            self.status = Status::Aborted;
+        }
+    }
+
+    fn complete(&mut self) {
+        if self.editor.commit(&mut self.value) {
+            self.editor.input.complete();
+            self.status = Status::Done;
+        }
+    }
+
+    fn abort(&mut self) {
+        self.editor.input.abort();
+        self.status = Status::Aborted;
+    }
+
+    fn delete(&mut self) {
+        self.editor.input.delete();
+    }
+
+    fn backspace(&mut self) {
+        self.editor.input.backspace();
+    }
+
+    fn kill(&mut self) {
+        self.editor.input.kill();
+    }
+
+    fn truncate(&mut self) {
+        self.editor.input.truncate();
+    }
+
+    fn push(&mut self, c: char) {
+        self.editor.input.push(c);
+    }
+}
+
+impl From<Time> for TimeState<'_> {
+    fn from(time: Time) -> Self {
+        Self::new().with_time(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+    use time::macros::time;
+
+    use super::*;
+    use crate::test_utils::type_str;
+
+    #[test]
+    fn new() {
+        let state = TimeState::new();
+        assert_eq!(state.status(), Status::Pending);
+        assert_eq!(state.time(), Time::MIDNIGHT);
+        assert_eq!(state.value(), "00:00");
+        assert_eq!(state.segment(), 0);
+        assert_eq!(state.segment_range(), Some(0..2));
+    }
+
+    #[test]
+    fn segments() {
+        let mut state = TimeState::from(time!(14:30)).with_seconds(true);
+        assert_eq!(state.value(), "14:30:00");
+        state.handle_key_event(KeyCode::Right.into());
+        assert_eq!(state.segment(), 1);
+        assert_eq!(state.segment_range(), Some(3..5));
+        assert_eq!(state.position(), 3);
+        state.handle_key_event(KeyCode::Right.into());
+        state.handle_key_event(KeyCode::Right.into());
+        assert_eq!(state.segment(), 2);
+        state.handle_key_event(KeyCode::Left.into());
+        assert_eq!(state.segment(), 1);
+    }
+
+    #[test]
+    fn step_with_rollover() {
+        let mut state = TimeState::from(time!(23:59));
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.time(), time!(00:59));
+        state.handle_key_event(KeyCode::Right.into());
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.time(), time!(00:00));
+        state.handle_key_event(KeyCode::Down.into());
+        assert_eq!(state.time(), time!(00:59));
+        assert_eq!(state.value(), "00:59");
+    }
+
+    #[test]
+    fn twelve_hour() {
+        let mut state = TimeState::from(time!(12:15)).with_twelve_hour(true);
+        assert_eq!(state.value(), "12:15 PM");
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.time(), time!(13:15));
+        assert_eq!(state.value(), "01:15 PM");
+        state.handle_key_event(KeyCode::Down.into());
+        state.handle_key_event(KeyCode::Down.into());
+        assert_eq!(state.value(), "11:15 PM");
+        state.handle_key_event(KeyCode::Right.into());
+        state.handle_key_event(KeyCode::Right.into());
+        assert_eq!(state.segment_range(), Some(6..8));
+        state.handle_key_event(KeyCode::Up.into());
+        assert_eq!(state.time(), time!(11:15));
+        assert_eq!(state.value(), "11:15 AM");
+    }
+
+    #[test]
+    fn type_time() {
+        let mut state = TimeState::from(time!(09:00));
+        type_str(&mut state, "14:3");
+        assert!(state.is_typing());
+        assert_eq!(state.value(), "14:3");
+        assert_eq!(state.segment_range(), None);
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Done);
+        assert_eq!(state.time(), time!(14:03));
+        assert_eq!(state.value(), "14:03");
+    }
+
+    #[test]
+    fn type_twelve_hour() {
+        let mut state = TimeState::new().with_twelve_hour(true).with_seconds(true);
+        type_str(&mut state, "2:30:15 pm");
+        state.handle_key_event(KeyCode::Right.into());
+        assert!(!state.is_typing());
+        assert_eq!(state.time(), time!(14:30:15));
+        assert_eq!(state.value(), "02:30:15 PM");
+        assert_eq!(state.segment(), 1);
+    }
+
+    #[test]
+    fn backspace_edits_value() {
+        let mut state = TimeState::from(time!(14:30));
+        state.handle_key_event(KeyCode::Backspace.into());
+        assert_eq!(state.value(), "14:3");
+        assert!(state.is_typing());
+    }
+
+    #[rstest::rstest]
+    #[case::hour("24:00", false)]
+    #[case::minute("12:60", false)]
+    #[case::digits("123:00", false)]
+    #[case::missing_minutes("12", false)]
+    #[case::missing_meridiem("12:00", true)]
+    #[case::zero_hour("0:00 am", true)]
+    fn invalid(#[case] value: &str, #[case] twelve_hour: bool) {
+        let mut state = TimeState::from(time!(09:30)).with_twelve_hour(twelve_hour);
+        type_str(&mut state, value);
+        state.handle_key_event(KeyCode::Enter.into());
+        assert_eq!(state.status(), Status::Pending);
+        assert!(state
+            .error()
+            .unwrap()
+            .starts_with("Invalid time, e.g. 09:30"));
+        assert_eq!(state.time(), time!(09:30));
+        state.handle_key_event(KeyCode::Backspace.into());
+        assert_eq!(state.error(), None);
+    }
+
+    #[test]
+    fn invalid_blocks_navigation() {
+        let mut state = TimeState::from(time!(09:30));
+        type_str(&mut state, "99");
+        state.handle_key_event(KeyCode::Right.into());
+        assert_eq!(state.error(), Some("Invalid time, e.g. 09:30"));
+        assert_eq!(state.segment(), 0);
+        assert_eq!(state.value(), "99");
+    }
+
+    #[test]
+    fn abort() {
+        let mut state = TimeState::new();
+        state.handle_key_event(KeyCode::Esc.into());
+        assert_eq!(state.status(), Status::Aborted);
+    }
+}
@@ -0,0 +1,271 @@
+//! Derive macro for [tui-prompts](https://crates.io/crates/tui-prompts).
+//!
+//! This crate is re-exported by `tui-prompts` with the `derive` feature, see `PromptForm` for the
+//! supported fields and attributes.
+#![warn(clippy::pedantic, clippy::nursery, clippy::cargo, unused)]
+#![allow(clippy::multiple_crate_versions)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DataEnum, DataStruct, DeriveInput, Error, Fields,
+    LitStr, Path, Result,
+};
+
+/// Derives `PromptForm` for a struct with named fields, or `Ask` and `Choice` for an enum with
+/// unit variants.
+///
+/// A struct field accepts `#[prompt(message = "...", default = "...", validate = path, password)]`.
+/// `validate` and `password` require a text field, i.e. a type that implements `AskText`:
+///
+/// ```compile_fail
+/// #[derive(tui_prompts::Prompt)]
+/// struct Options {
+///     #[prompt(password)]
+///     verbose: bool,
+/// }
+/// ```
+///
+/// The `default` of a `bool` field is `yes`, `no`, `true` or `false`:
+///
+/// ```compile_fail
+/// #[derive(tui_prompts::Prompt)]
+/// struct Options {
+///     #[prompt(default = "y")]
+///     verbose: bool,
+/// }
+/// ```
+#[proc_macro_derive(Prompt, attributes(prompt))]
+pub fn derive_prompt(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = match &input.data {
+        Data::Struct(data) => derive_struct(&input, data),
+        Data::Enum(data) => derive_enum(&input, data),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "Prompt can only be derived for structs and enums",
+        )),
+    };
+    expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// The options of the `#[prompt(...)]` attribute of a struct field.
+#[derive(Default)]
+struct FieldOptions {
+    message: Option<LitStr>,
+    default: Option<LitStr>,
+    validate: Option<Path>,
+    password: bool,
+}
+
+impl FieldOptions {
+    fn parse(field: &syn::Field) -> Result<Self> {
+        let mut options = Self::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("prompt"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("message") {
+                    options.message = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("default") {
+                    options.default = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("validate") {
+                    options.validate = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("password") {
+                    options.password = true;
+                } else {
+                    return Err(
+                        meta.error("expected `message`, `default`, `validate` or `password`")
+                    );
+                }
+                Ok(())
+            })?;
+        }
+        if let Some(default) = &options.default {
+            if is_bool(&field.ty) && !BOOL_DEFAULTS.contains(&default.value().as_str()) {
+                return Err(Error::new_spanned(
+                    default,
+                    "expected `yes`, `no`, `true` or `false` as the default of a bool field",
+                ));
+            }
+        }
+        Ok(options)
+    }
+
+    /// The expression that builds the `AskOptions` of the field.
+    fn to_tokens(&self, name: &str) -> TokenStream2 {
+        let message = self
+            .message
+            .as_ref()
+            .map_or_else(|| humanize(name), LitStr::value);
+        let mut tokens = quote! {
+            ::tui_prompts::AskOptions::new(::std::borrow::Cow::Borrowed(#message))
+                .with_theme(theme.clone())
+        };
+        if let Some(default) = &self.default {
+            tokens = quote! { #tokens.with_default(#default) };
+        }
+        if let Some(validate) = &self.validate {
+            tokens = quote! { #tokens.with_validator(#validate) };
+        }
+        if self.password {
+            tokens = quote! { #tokens.with_password(true) };
+        }
+        tokens
+    }
+
+    /// Asserts that the field is a text field if an option applies only to text fields, so that
+    /// other fields fail to compile.
+    fn assert_text(&self, ty: &syn::Type) -> Option<TokenStream2> {
+        (self.validate.is_some() || self.password).then(|| {
+            quote_spanned! { ty.span()=>
+                fn text_field<T: ::tui_prompts::AskText + ?::std::marker::Sized>() {}
+                text_field::<#ty>();
+            }
+        })
+    }
+}
+
+fn derive_struct(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream2> {
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Prompt can only be derived for structs with named fields",
+        ));
+    };
+    let mut assertions = Vec::new();
+    let mut questions = Vec::new();
+    let mut values = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("named fields have an ident");
+        let ty = &field.ty;
+        let name = ident.to_string();
+        let options = FieldOptions::parse(field)?;
+        assertions.extend(options.assert_text(ty));
+        let options = options.to_tokens(&name);
+        questions.push(quote! {
+            .with_question(<#ty as ::tui_prompts::Ask>::question(#name, #options))
+        });
+        values.push(quote! {
+            #ident: <#ty as ::tui_prompts::FromAnswer>::from_answer(answers.get(#name)?)?
+        });
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tui_prompts::PromptForm for #ident #ty_generics #where_clause {
+            fn questionnaire(
+                theme: &::tui_prompts::Theme,
+            ) -> ::tui_prompts::QuestionnaireState<'static> {
+                #({ #assertions })*
+                ::tui_prompts::QuestionnaireState::new() #(#questions)*
+            }
+
+            fn from_answers(answers: &::tui_prompts::Answers) -> ::std::option::Option<Self> {
+                ::std::option::Option::Some(Self { #(#values,)* })
+            }
+        }
+    })
+}
+
+fn derive_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
+    let mut names = Vec::new();
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "Prompt can only be derived for enums with unit variants",
+            ));
+        }
+        names.push(variant.ident.to_string());
+        variants.push(&variant.ident);
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tui_prompts::Choice for #ident #ty_generics #where_clause {
+            fn choices() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            fn from_choice(choice: &str) -> ::std::option::Option<Self> {
+                match choice {
+                    #(#names => ::std::option::Option::Some(Self::#variants),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+
+        impl #impl_generics ::tui_prompts::FromAnswer for #ident #ty_generics #where_clause {
+            fn from_answer(answer: &::tui_prompts::Answer) -> ::std::option::Option<Self> {
+                answer
+                    .as_text()
+                    .and_then(<Self as ::tui_prompts::Choice>::from_choice)
+            }
+        }
+
+        impl #impl_generics ::tui_prompts::Ask for #ident #ty_generics #where_clause {
+            fn question(
+                name: &'static str,
+                options: ::tui_prompts::AskOptions,
+            ) -> ::tui_prompts::Question<'static> {
+                options.choice_question::<Self>(name)
+            }
+        }
+    })
+}
+
+/// The defaults that the question of a `bool` field accepts.
+const BOOL_DEFAULTS: [&str; 4] = ["yes", "no", "true", "false"];
+
+fn is_bool(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path) if path.qself.is_none() && path.path.is_ident("bool"))
+}
+
+/// The default message of a field, e.g. `Project name` for `project_name`.
+fn humanize(name: &str) -> String {
+    let name = name.trim_start_matches("r#").replace('_', " ");
+    let mut chars = name.trim().chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(input: TokenStream2) -> syn::Field {
+        let input: DeriveInput = syn::parse2(input).unwrap();
+        let Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) = input.data
+        else {
+            panic!("expected a struct with named fields");
+        };
+        fields.named.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn bool_defaults() {
+        let yes = field(quote! { struct Options { #[prompt(default = "yes")] verbose: bool } });
+        assert!(FieldOptions::parse(&yes).is_ok());
+        let y = field(quote! { struct Options { #[prompt(default = "y")] verbose: bool } });
+        assert!(FieldOptions::parse(&y).is_err());
+        let text = field(quote! { struct Options { #[prompt(default = "y")] name: String } });
+        assert!(FieldOptions::parse(&text).is_ok());
+    }
+
+    #[test]
+    fn humanize_names() {
+        assert_eq!(humanize("name"), "Name");
+        assert_eq!(humanize("project_name"), "Project name");
+        assert_eq!(humanize("r#type"), "Type");
+    }
+}
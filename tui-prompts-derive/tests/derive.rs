@@ -0,0 +1,129 @@
+use ratatui::crossterm::event::KeyCode;
+use tui_prompts::{prelude::*, Prompt};
+
+#[derive(Debug, PartialEq, Eq, Prompt)]
+enum License {
+    Mit,
+    Apache,
+}
+
+#[derive(Debug, PartialEq, Eq, Prompt)]
+struct Project {
+    #[prompt(message = "Project name", validate = not_empty)]
+    name: String,
+    description: Option<String>,
+    license: License,
+    #[prompt(default = "yes")]
+    git_repository: bool,
+    #[prompt(password)]
+    token: String,
+}
+
+fn not_empty(value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        Err("Required".into())
+    } else {
+        Ok(())
+    }
+}
+
+fn press(state: &mut QuestionnaireState, keys: &[KeyCode]) {
+    for key in keys {
+        state.handle_key_event((*key).into());
+    }
+}
+
+fn type_str(state: &mut QuestionnaireState, value: &str) {
+    for c in value.chars() {
+        state.handle_key_event(KeyCode::Char(c).into());
+    }
+}
+
+#[test]
+fn questions() {
+    let state = Project::questionnaire(&Theme::default());
+    let names: Vec<_> = state.questions().iter().map(Question::name).collect();
+    assert_eq!(
+        names,
+        ["name", "description", "license", "git_repository", "token"]
+    );
+}
+
+#[test]
+fn choices() {
+    assert_eq!(License::choices(), ["Mit", "Apache"]);
+    assert_eq!(License::from_choice("Apache"), Some(License::Apache));
+    assert_eq!(License::from_choice("Gpl"), None);
+}
+
+#[test]
+fn from_answers() {
+    let mut state = Project::questionnaire(&Theme::default());
+    // the validator keeps the first question pending
+    press(&mut state, &[KeyCode::Enter]);
+    assert_eq!(state.current(), Some(0));
+    type_str(&mut state, "tui");
+    press(
+        &mut state,
+        &[
+            KeyCode::Enter,
+            KeyCode::Enter,
+            KeyCode::Down,
+            KeyCode::Enter,
+        ],
+    );
+    press(&mut state, &[KeyCode::Enter]);
+    type_str(&mut state, "secret");
+    press(&mut state, &[KeyCode::Enter]);
+    let answers = state.into_answers().unwrap();
+    assert!(answers.get("token").and_then(Answer::as_secret).is_some());
+    assert_eq!(
+        Project::from_answers(&answers),
+        Some(Project {
+            name: "tui".into(),
+            description: None,
+            license: License::Apache,
+            git_repository: true,
+            token: "secret".into(),
+        })
+    );
+}
+
+#[test]
+fn prefill() {
+    let prefill = Prefill::new()
+        .with_value("name", "tui")
+        .with_value("description", "")
+        .with_value("license", "Apache")
+        .with_value("git_repository", "no")
+        .with_value("token", "secret");
+    let state = Project::questionnaire(&Theme::default()).with_prefill(prefill);
+    let answers = state.into_answers().unwrap();
+    assert_eq!(
+        Project::from_answers(&answers),
+        Some(Project {
+            name: "tui".into(),
+            description: None,
+            license: License::Apache,
+            git_repository: false,
+            token: "secret".into(),
+        })
+    );
+}
+
+#[test]
+fn messages() {
+    let mut state = Project::questionnaire(&Theme::default().no_color());
+    let mut buffer = ratatui::buffer::Buffer::empty(ratatui::layout::Rect::new(0, 0, 30, 1));
+    ratatui::widgets::StatefulWidget::render(
+        Questionnaire::new(),
+        buffer.area,
+        &mut buffer,
+        &mut state,
+    );
+    buffer.set_style(buffer.area, ratatui::style::Style::reset());
+    assert_eq!(
+        buffer,
+        ratatui::buffer::Buffer::with_lines(["? Project name ›              "])
+    );
+}